@@ -0,0 +1,475 @@
+/// Iterators over arrangements of indices, the current arrangement is lent out without allocating
+///
+/// Every implementor is also an `Iterator` of owned `Vec<usize>` for convenience.
+pub trait IndexArrangements {
+    /// Advances to the next arrangement, the slice is valid until the following call
+    fn next_indices(&mut self) -> Option<&[usize]>;
+
+    /// Maps each arrangement onto `items`, e.g. `Permutations::new(items.len(), 2).select(&items)`
+    fn select<T>(self, items: &[T]) -> Selected<'_, Self, T>
+    where
+        Self: Sized,
+    {
+        Selected { arrangements: self, items }
+    }
+}
+
+/// Arrangements mapped onto references to the items they index
+pub struct Selected<'a, A, T> {
+    arrangements: A,
+    items: &'a [T],
+}
+
+impl<'a, A, T> Iterator for Selected<'a, A, T>
+where
+    A: IndexArrangements,
+{
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let items = self.items;
+        self.arrangements.next_indices().map(|indices| indices.iter().map(|&i| &items[i]).collect())
+    }
+}
+
+macro_rules! impl_iterator {
+    ($($t:ty),*) => {
+        $(
+            impl Iterator for $t {
+                type Item = Vec<usize>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    self.next_indices().map(<[usize]>::to_vec)
+                }
+            }
+        )*
+    };
+}
+
+/// Ordered selections of `k` out of `n` indices without repetition, in lexicographic order
+pub struct Permutations {
+    n: usize,
+    k: usize,
+    indices: Vec<usize>,
+    cycles: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl Permutations {
+    pub fn new(n: usize, k: usize) -> Self {
+        Self {
+            n,
+            k,
+            indices: (0..n).collect(),
+            cycles: (n.saturating_sub(k) + 1..=n).rev().collect(),
+            started: false,
+            done: k > n,
+        }
+    }
+}
+
+impl IndexArrangements for Permutations {
+    fn next_indices(&mut self) -> Option<&[usize]> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(&self.indices[..self.k]);
+        }
+
+        for i in (0..self.k).rev() {
+            self.cycles[i] -= 1;
+            if self.cycles[i] == 0 {
+                // Move index i to the end and reset its cycle
+                self.indices[i..].rotate_left(1);
+                self.cycles[i] = self.n - i;
+            } else {
+                let j = self.n - self.cycles[i];
+                self.indices.swap(i, j);
+                return Some(&self.indices[..self.k]);
+            }
+        }
+        self.done = true;
+        None
+    }
+}
+
+/// Unordered selections of `k` out of `n` indices, each in ascending order, in lexicographic order
+pub struct Combinations {
+    n: usize,
+    indices: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl Combinations {
+    pub fn new(n: usize, k: usize) -> Self {
+        Self {
+            n,
+            indices: (0..k).collect(),
+            started: false,
+            done: k > n,
+        }
+    }
+}
+
+impl IndexArrangements for Combinations {
+    fn next_indices(&mut self) -> Option<&[usize]> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(&self.indices);
+        }
+
+        let k = self.indices.len();
+        // The rightmost index that has not reached its maximum
+        let Some(i) = (0..k).rev().find(|&i| self.indices[i] != i + self.n - k) else {
+            self.done = true;
+            return None;
+        };
+        self.indices[i] += 1;
+        for j in i + 1..k {
+            self.indices[j] = self.indices[j - 1] + 1;
+        }
+        Some(&self.indices)
+    }
+}
+
+/// Unordered selections of `k` out of `n` indices where an index may repeat, each in
+/// non-decreasing order, in lexicographic order
+pub struct CombinationsWithReplacement {
+    n: usize,
+    indices: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl CombinationsWithReplacement {
+    pub fn new(n: usize, k: usize) -> Self {
+        Self {
+            n,
+            indices: vec![0; k],
+            started: false,
+            done: n == 0 && k > 0,
+        }
+    }
+}
+
+impl IndexArrangements for CombinationsWithReplacement {
+    fn next_indices(&mut self) -> Option<&[usize]> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(&self.indices);
+        }
+
+        let Some(i) = self.indices.iter().rposition(|&index| index != self.n - 1) else {
+            self.done = true;
+            return None;
+        };
+        let next = self.indices[i] + 1;
+        self.indices[i..].fill(next);
+        Some(&self.indices)
+    }
+}
+
+/// Every choice of one index per dimension, with the last dimension changing fastest
+pub struct CartesianProduct {
+    sizes: Vec<usize>,
+    indices: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl CartesianProduct {
+    /// The product of dimensions with the given sizes, e.g. the lengths of N iterables
+    pub fn new(sizes: &[usize]) -> Self {
+        Self {
+            sizes: sizes.to_vec(),
+            indices: vec![0; sizes.len()],
+            started: false,
+            done: sizes.contains(&0),
+        }
+    }
+
+    /// `repeat` dimensions of the same size, e.g. the operator to place in each of `repeat` gaps
+    pub fn repeat(size: usize, repeat: usize) -> Self {
+        Self::new(&vec![size; repeat])
+    }
+}
+
+impl IndexArrangements for CartesianProduct {
+    fn next_indices(&mut self) -> Option<&[usize]> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            return Some(&self.indices);
+        }
+
+        for i in (0..self.indices.len()).rev() {
+            self.indices[i] += 1;
+            if self.indices[i] < self.sizes[i] {
+                return Some(&self.indices);
+            }
+            self.indices[i] = 0;
+        }
+        self.done = true;
+        None
+    }
+}
+
+/// Every permutation of `n` indices by Heap's algorithm, consecutive permutations differ by
+/// swapping a single pair, which suits incrementally updated state
+pub struct HeapPermutations {
+    indices: Vec<usize>,
+    counters: Vec<usize>,
+    position: usize,
+    started: bool,
+}
+
+impl HeapPermutations {
+    pub fn new(n: usize) -> Self {
+        Self {
+            indices: (0..n).collect(),
+            counters: vec![0; n],
+            position: 1,
+            started: false,
+        }
+    }
+}
+
+impl IndexArrangements for HeapPermutations {
+    fn next_indices(&mut self) -> Option<&[usize]> {
+        if !self.started {
+            self.started = true;
+            return Some(&self.indices);
+        }
+
+        while self.position < self.indices.len() {
+            let i = self.position;
+            if self.counters[i] < i {
+                if i.is_multiple_of(2) {
+                    self.indices.swap(0, i);
+                } else {
+                    self.indices.swap(self.counters[i], i);
+                }
+                self.counters[i] += 1;
+                self.position = 1;
+                return Some(&self.indices);
+            }
+            self.counters[i] = 0;
+            self.position += 1;
+        }
+        None
+    }
+}
+
+impl_iterator!(Permutations, Combinations, CombinationsWithReplacement, CartesianProduct, HeapPermutations);
+
+/// Every subset of `n` items as a bitmask, bit `i` set when item `i` is included, from the empty
+/// set upwards
+pub struct PowerSet {
+    next: u64,
+    end: u64,
+}
+
+impl PowerSet {
+    /// Panics if `n` is 64 or more
+    pub fn new(n: usize) -> Self {
+        assert!(n < 64, "Power sets are limited to 63 items");
+        Self { next: 0, end: 1 << n }
+    }
+}
+
+impl Iterator for PowerSet {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.next < self.end).then(|| {
+            self.next += 1;
+            self.next - 1
+        })
+    }
+}
+
+/// The indices of the set bits of a subset mask in ascending order
+pub fn subset_indices(mask: u64) -> impl Iterator<Item = usize> {
+    (0..64).filter(move |&i| mask & (1 << i) != 0)
+}
+
+/// The number of ways to choose `k` of `n` items, None on overflow
+pub fn n_choose_k(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        // Exact at every step as it is itself a binomial coefficient
+        result = result * (n - i) as u128 / (i + 1) as u128;
+        if result > u64::MAX as u128 {
+            return None;
+        }
+    }
+    Some(result as u64)
+}
+
+/// The number of ordered selections of `k` of `n` items, None on overflow
+pub fn n_permute_k(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    (n - k + 1..=n).try_fold(1u64, |acc, x| acc.checked_mul(x))
+}
+
+/// The number of distinct orderings of a multiset with the given multiplicities, None on overflow
+pub fn multinomial(counts: &[u64]) -> Option<u64> {
+    let mut total = 0u64;
+    let mut result = 1u64;
+    for &count in counts {
+        total = total.checked_add(count)?;
+        result = result.checked_mul(n_choose_k(total, count)?)?;
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn permutations_lexicographic() {
+        let all = Permutations::new(3, 3).collect::<Vec<_>>();
+        assert_eq!(all, vec![vec![0, 1, 2], vec![0, 2, 1], vec![1, 0, 2], vec![1, 2, 0], vec![2, 0, 1], vec![2, 1, 0]]);
+
+        let pairs = Permutations::new(3, 2).collect::<Vec<_>>();
+        assert_eq!(pairs, vec![vec![0, 1], vec![0, 2], vec![1, 0], vec![1, 2], vec![2, 0], vec![2, 1]]);
+
+        assert_eq!(Permutations::new(6, 3).count() as u64, n_permute_k(6, 3).unwrap());
+        assert_eq!(Permutations::new(2, 3).count(), 0);
+        assert_eq!(Permutations::new(4, 0).collect::<Vec<_>>(), vec![Vec::<usize>::new()]);
+    }
+
+    #[test]
+    fn combinations_lexicographic() {
+        let all = Combinations::new(4, 2).collect::<Vec<_>>();
+        assert_eq!(all, vec![vec![0, 1], vec![0, 2], vec![0, 3], vec![1, 2], vec![1, 3], vec![2, 3]]);
+        assert_eq!(Combinations::new(10, 4).count() as u64, n_choose_k(10, 4).unwrap());
+        assert_eq!(Combinations::new(3, 4).count(), 0);
+        assert_eq!(Combinations::new(3, 0).count(), 1);
+    }
+
+    #[test]
+    fn combinations_with_replacement() {
+        let all = CombinationsWithReplacement::new(3, 2).collect::<Vec<_>>();
+        assert_eq!(all, vec![vec![0, 0], vec![0, 1], vec![0, 2], vec![1, 1], vec![1, 2], vec![2, 2]]);
+        // Multisets of size k from n items: C(n + k - 1, k)
+        assert_eq!(CombinationsWithReplacement::new(5, 3).count() as u64, n_choose_k(7, 3).unwrap());
+        assert_eq!(CombinationsWithReplacement::new(0, 2).count(), 0);
+    }
+
+    #[test]
+    fn cartesian_product() {
+        let all = CartesianProduct::new(&[2, 3]).collect::<Vec<_>>();
+        assert_eq!(all, vec![vec![0, 0], vec![0, 1], vec![0, 2], vec![1, 0], vec![1, 1], vec![1, 2]]);
+        assert_eq!(CartesianProduct::repeat(3, 4).count(), 81);
+        assert_eq!(CartesianProduct::new(&[2, 0, 2]).count(), 0);
+        assert_eq!(CartesianProduct::new(&[]).count(), 1);
+    }
+
+    #[test]
+    fn heap_permutations_single_swaps() {
+        let mut heap = HeapPermutations::new(4);
+        let mut seen = HashSet::new();
+        let mut previous: Option<Vec<usize>> = None;
+        while let Some(indices) = heap.next_indices() {
+            if let Some(previous) = &previous {
+                assert_eq!(previous.iter().zip(indices).filter(|(a, b)| a != b).count(), 2);
+            }
+            seen.insert(indices.to_vec());
+            previous = Some(indices.to_vec());
+        }
+        assert_eq!(seen.len(), 24);
+        assert_eq!(HeapPermutations::new(0).count(), 1);
+        assert_eq!(HeapPermutations::new(1).count(), 1);
+    }
+
+    #[test]
+    fn power_set() {
+        let subsets = PowerSet::new(3).map(|mask| subset_indices(mask).collect::<Vec<_>>()).collect::<Vec<_>>();
+        assert_eq!(subsets.len(), 8);
+        assert_eq!(subsets[0], Vec::<usize>::new());
+        assert_eq!(subsets[5], vec![0, 2]);
+        assert_eq!(subsets[7], vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn counting_with_overflow() {
+        assert_eq!(n_choose_k(5, 2), Some(10));
+        assert_eq!(n_choose_k(5, 7), Some(0));
+        assert_eq!(n_choose_k(67, 33), Some(14_226_520_737_620_288_370));
+        assert_eq!(n_choose_k(68, 34), None);
+        assert_eq!(n_permute_k(20, 20), Some(2_432_902_008_176_640_000));
+        assert_eq!(n_permute_k(21, 21), None);
+        // MISSISSIPPI
+        assert_eq!(multinomial(&[1, 4, 4, 2]), Some(34_650));
+        assert_eq!(multinomial(&[]), Some(1));
+        assert_eq!(multinomial(&[30, 30, 30]), None);
+    }
+
+    #[test]
+    fn shortest_and_longest_route() {
+        let cities = ["London", "Dublin", "Belfast"];
+        let distance = |a: &str, b: &str| match (a.min(b), a.max(b)) {
+            ("Dublin", "London") => 464,
+            ("Belfast", "London") => 518,
+            ("Belfast", "Dublin") => 141,
+            _ => unreachable!(),
+        };
+        let lengths = Permutations::new(cities.len(), cities.len())
+            .select(&cities)
+            .map(|route| route.windows(2).map(|w| distance(w[0], w[1])).sum::<u32>())
+            .collect::<Vec<_>>();
+        assert_eq!(lengths.iter().min(), Some(&605));
+        assert_eq!(lengths.iter().max(), Some(&982));
+    }
+
+    #[test]
+    fn operator_insertion() {
+        let equations: [(u64, &[u64]); 9] = [
+            (190, &[10, 19]),
+            (3267, &[81, 40, 27]),
+            (83, &[17, 5]),
+            (156, &[15, 6]),
+            (7290, &[6, 8, 6, 15]),
+            (161011, &[16, 10, 13]),
+            (192, &[17, 8, 14]),
+            (21037, &[9, 7, 18, 13]),
+            (292, &[11, 6, 16, 20]),
+        ];
+        let solvable = |target: u64, numbers: &[u64]| {
+            let mut operators = CartesianProduct::repeat(2, numbers.len() - 1);
+            while let Some(ops) = operators.next_indices() {
+                let value = ops.iter().zip(&numbers[1..]).fold(numbers[0], |acc, (&op, &n)| match op {
+                    0 => acc + n,
+                    _ => acc * n,
+                });
+                if value == target {
+                    return true;
+                }
+            }
+            false
+        };
+        let total = equations.iter().filter(|(t, n)| solvable(*t, n)).map(|(t, _)| t).sum::<u64>();
+        assert_eq!(total, 3749);
+    }
+}
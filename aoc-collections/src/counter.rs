@@ -0,0 +1,291 @@
+use std::collections::hash_map;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::numeric::Integer;
+
+/// A multiset counting occurrences of each element
+///
+/// Only positive counts are stored, an element with no (or a zero) count is absent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<T, N = usize>
+where
+    T: Eq + Hash,
+{
+    counts: HashMap<T, N>,
+}
+
+impl<T, N> Counter<T, N>
+where
+    T: Eq + Hash,
+    N: Integer,
+{
+    pub fn new() -> Self {
+        Self { counts: HashMap::new() }
+    }
+
+    /// Counts one occurrence of `item`
+    pub fn insert(&mut self, item: T) {
+        self.insert_weighted(item, N::ONE);
+    }
+
+    /// Counts `weight` occurrences of `item`
+    pub fn insert_weighted(&mut self, item: T, weight: N) {
+        if weight <= N::default() {
+            return;
+        }
+        *self.counts.entry(item).or_default() += weight;
+    }
+
+    /// Removes up to `weight` occurrences of `item`, dropping it once its count reaches zero
+    pub fn remove_weighted(&mut self, item: &T, weight: N) {
+        if let Some(count) = self.counts.get_mut(item) {
+            *count = count.clamped_sub(weight);
+            if *count == N::default() {
+                self.counts.remove(item);
+            }
+        }
+    }
+
+    /// The count of `item`, zero if it has never been inserted
+    pub fn get(&self, item: &T) -> N {
+        self.counts.get(item).copied().unwrap_or_default()
+    }
+
+    pub fn contains(&self, item: &T) -> bool {
+        self.counts.contains_key(item)
+    }
+
+    /// Number of distinct elements
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Sum of all counts
+    pub fn total(&self) -> N {
+        self.counts.values().fold(N::default(), |acc, &c| acc + c)
+    }
+
+    /// Iterates `(element, count)` pairs in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = (&T, N)> {
+        self.counts.iter().map(|(k, &v)| (k, v))
+    }
+
+    pub fn into_map(self) -> HashMap<T, N> {
+        self.counts
+    }
+}
+
+impl<T, N> Counter<T, N>
+where
+    T: Eq + Hash + Ord,
+    N: Integer,
+{
+    /// The `k` most common elements, highest count first with ties ordered by element
+    pub fn most_common(&self, k: usize) -> Vec<(&T, N)> {
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        entries.truncate(k);
+        entries
+    }
+
+    /// `(element, count)` pairs ordered by element
+    pub fn sorted(&self) -> Vec<(&T, N)> {
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries
+    }
+}
+
+impl<T, N> Counter<T, N>
+where
+    T: Eq + Hash + Clone,
+    N: Integer,
+{
+    /// Counts of both counters added together
+    pub fn add(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for (item, count) in other.iter() {
+            result.insert_weighted(item.clone(), count);
+        }
+        result
+    }
+
+    /// Counts of `other` taken away, elements whose count drops to zero or below are removed
+    pub fn subtract(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for (item, count) in other.iter() {
+            result.remove_weighted(item, count);
+        }
+        result
+    }
+
+    /// The maximum count of each element in either counter
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for (item, count) in other.iter() {
+            let entry = result.counts.entry(item.clone()).or_default();
+            *entry = (*entry).max(count);
+        }
+        result
+    }
+
+    /// The minimum count of each element present in both counters
+    pub fn intersection(&self, other: &Self) -> Self {
+        let counts = self
+            .iter()
+            .filter(|(item, _)| other.contains(item))
+            .map(|(item, count)| (item.clone(), count.min(other.get(item))))
+            .collect();
+        Self { counts }
+    }
+}
+
+impl<T, N> Default for Counter<T, N>
+where
+    T: Eq + Hash,
+    N: Integer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, N> FromIterator<T> for Counter<T, N>
+where
+    T: Eq + Hash,
+    N: Integer,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T, N> FromIterator<(T, N)> for Counter<T, N>
+where
+    T: Eq + Hash,
+    N: Integer,
+{
+    fn from_iter<I: IntoIterator<Item = (T, N)>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T, N> Extend<T> for Counter<T, N>
+where
+    T: Eq + Hash,
+    N: Integer,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl<T, N> Extend<(T, N)> for Counter<T, N>
+where
+    T: Eq + Hash,
+    N: Integer,
+{
+    fn extend<I: IntoIterator<Item = (T, N)>>(&mut self, iter: I) {
+        for (item, weight) in iter {
+            self.insert_weighted(item, weight);
+        }
+    }
+}
+
+impl<T, N> IntoIterator for Counter<T, N>
+where
+    T: Eq + Hash,
+{
+    type Item = (T, N);
+    type IntoIter = hash_map::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_owned_and_borrowed() {
+        let input = vec![1, 1, 2, 1, 2, 3, 1, 2, 3, 4, 1, 2, 3, 4, 5, 6];
+
+        let borrowed: Counter<&i32> = input.iter().collect();
+        assert_eq!(borrowed.get(&&1), 5);
+        assert_eq!(borrowed.get(&&6), 1);
+        assert_eq!(borrowed.get(&&7), 0);
+
+        let owned: Counter<i32> = input.into_iter().collect();
+        assert_eq!(owned.get(&2), 4);
+        assert_eq!(owned.len(), 6);
+        assert_eq!(owned.total(), 16);
+    }
+
+    #[test]
+    fn count_chars() {
+        let counter: Counter<char, u32> = "abracadabra".chars().collect();
+        assert_eq!(counter.most_common(2), vec![(&'a', 5), (&'b', 2)]);
+        assert_eq!(counter.most_common(3), vec![(&'a', 5), (&'b', 2), (&'r', 2)]);
+        assert_eq!(
+            counter.sorted(),
+            vec![(&'a', 5), (&'b', 2), (&'c', 1), (&'d', 1), (&'r', 2)]
+        );
+    }
+
+    #[test]
+    fn weighted_insert_and_remove() {
+        let mut counter: Counter<&str, u64> = Counter::new();
+        counter.insert_weighted("x", 10);
+        counter.insert("x");
+        counter.insert_weighted("y", 0);
+        assert_eq!(counter.get(&"x"), 11);
+        assert!(!counter.contains(&"y"));
+
+        counter.remove_weighted(&"x", 4);
+        assert_eq!(counter.get(&"x"), 7);
+        counter.remove_weighted(&"x", 20);
+        assert!(counter.is_empty());
+    }
+
+    #[test]
+    fn counter_arithmetic() {
+        let a: Counter<char> = "aaabbc".chars().collect();
+        let b: Counter<char> = "abbbd".chars().collect();
+
+        assert_eq!(a.add(&b).sorted(), vec![(&'a', 4), (&'b', 5), (&'c', 1), (&'d', 1)]);
+        assert_eq!(a.subtract(&b).sorted(), vec![(&'a', 2), (&'c', 1)]);
+        assert_eq!(a.union(&b).sorted(), vec![(&'a', 3), (&'b', 3), (&'c', 1), (&'d', 1)]);
+        assert_eq!(a.intersection(&b).sorted(), vec![(&'a', 1), (&'b', 2)]);
+    }
+
+    #[test]
+    fn lanternfish_buckets() {
+        let mut fish: Counter<u8, u64> = [3, 4, 3, 1, 2].into_iter().collect();
+        for day in 1..=80 {
+            fish = fish
+                .into_iter()
+                .flat_map(|(timer, count)| match timer {
+                    0 => vec![(6, count), (8, count)],
+                    t => vec![(t - 1, count)],
+                })
+                .collect();
+            if day == 18 {
+                assert_eq!(fish.total(), 26);
+            }
+        }
+        assert_eq!(fish.total(), 5934);
+    }
+}
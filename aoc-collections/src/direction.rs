@@ -0,0 +1,160 @@
+use std::str::FromStr;
+
+/// Compass direction, North is up (towards row 0) on a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The 4 orthogonal directions clockwise from North
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// All 8 directions clockwise from North
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    fn rotate(self, eighths: usize) -> Direction {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Rotates 90 degrees anti-clockwise
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    /// Rotates 90 degrees clockwise
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Rotates 45 degrees anti-clockwise
+    pub fn turn_left_45(self) -> Direction {
+        self.rotate(7)
+    }
+
+    /// Rotates 45 degrees clockwise
+    pub fn turn_right_45(self) -> Direction {
+        self.rotate(1)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        (self as usize) % 2 == 1
+    }
+
+    /// The `[row, col]` offset of one step in this direction, as used by `Grid::offset_pos`
+    pub fn grid_offset(self) -> [isize; 2] {
+        match self {
+            Direction::North => [-1, 0],
+            Direction::NorthEast => [-1, 1],
+            Direction::East => [0, 1],
+            Direction::SouthEast => [1, 1],
+            Direction::South => [1, 0],
+            Direction::SouthWest => [1, -1],
+            Direction::West => [0, -1],
+            Direction::NorthWest => [-1, -1],
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    /// Parses the arrow (`^>v<`), compass (`NESW`) and up/down/left/right (`UDLR`) notations
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'N' | 'U' => Ok(Direction::North),
+            '>' | 'E' | 'R' => Ok(Direction::East),
+            'v' | 'S' | 'D' => Ok(Direction::South),
+            '<' | 'W' | 'L' => Ok(Direction::West),
+            _ => Err(format!("Invalid direction: {}", value)),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    /// Parses a single direction character (see `TryFrom<char>`) or a diagonal such as `NE`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction::NorthEast),
+            "SE" => Ok(Direction::SouthEast),
+            "SW" => Ok(Direction::SouthWest),
+            "NW" => Ok(Direction::NorthWest),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Direction::try_from(c),
+                    _ => Err(format!("Invalid direction: {}", s)),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::NorthEast.turn_right(), Direction::SouthEast);
+        assert_eq!(Direction::North.turn_left_45(), Direction::NorthWest);
+        assert_eq!(Direction::NorthWest.turn_right_45(), Direction::North);
+    }
+
+    #[test]
+    fn opposites() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.opposite().opposite(), direction);
+            let [r, c] = direction.grid_offset();
+            assert_eq!(direction.opposite().grid_offset(), [-r, -c]);
+        }
+        assert_eq!(Direction::SouthWest.opposite(), Direction::NorthEast);
+    }
+
+    #[test]
+    fn diagonals() {
+        assert_eq!(Direction::ALL.iter().filter(|d| d.is_diagonal()).count(), 4);
+        assert!(Direction::ORTHOGONAL.iter().all(|d| !d.is_diagonal()));
+    }
+
+    #[test]
+    fn parse_notations() {
+        let arrows = "^>v<".chars().map(|c| Direction::try_from(c).unwrap()).collect::<Vec<_>>();
+        let compass = "NESW".chars().map(|c| Direction::try_from(c).unwrap()).collect::<Vec<_>>();
+        let udlr = "URDL".chars().map(|c| Direction::try_from(c).unwrap()).collect::<Vec<_>>();
+        assert_eq!(arrows, Direction::ORTHOGONAL);
+        assert_eq!(compass, Direction::ORTHOGONAL);
+        assert_eq!(udlr, Direction::ORTHOGONAL);
+
+        assert_eq!("NW".parse::<Direction>(), Ok(Direction::NorthWest));
+        assert_eq!("v".parse::<Direction>(), Ok(Direction::South));
+        assert_eq!("x".parse::<Direction>(), Err("Invalid direction: x".to_string()));
+        assert_eq!("NNE".parse::<Direction>(), Err("Invalid direction: NNE".to_string()));
+    }
+}
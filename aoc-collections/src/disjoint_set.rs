@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Union-find over dense ids `0..len` with path compression and union by rank
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    /// Size of the component, only kept up to date for roots
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// `len` singleton sets
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// Adds a new singleton set and returns its id
+    pub fn add(&mut self) -> usize {
+        let id = self.parent.len();
+        self.parent.push(id);
+        self.rank.push(0);
+        self.size.push(1);
+        self.components += 1;
+        id
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the set containing `id`
+    pub fn find(&mut self, id: usize) -> usize {
+        let mut root = id;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Point everything on the way directly at the root
+        let mut current = id;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`, false if they were already the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (root, child) = if self.rank[a] >= self.rank[b] { (a, b) } else { (b, a) };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of disjoint sets
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Size of the set containing `id`
+    pub fn component_size(&mut self, id: usize) -> usize {
+        let root = self.find(id);
+        self.size[root]
+    }
+
+    /// Sizes of all sets, largest first
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut sizes = (0..self.len())
+            .filter(|&id| self.parent[id] == id)
+            .map(|id| self.size[id])
+            .collect::<Vec<_>>();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    /// The members of every set, each in ascending order and the sets ordered by their smallest member
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index_of_root = HashMap::new();
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        for id in 0..self.len() {
+            let root = self.find(id);
+            let index = *index_of_root.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[index].push(id);
+        }
+        components
+    }
+}
+
+/// Union-find over arbitrary keys, interned to dense ids in insertion order
+#[derive(Debug, Clone)]
+pub struct KeyedDisjointSet<K> {
+    ids: HashMap<K, usize>,
+    keys: Vec<K>,
+    sets: DisjointSet,
+}
+
+impl<K> KeyedDisjointSet<K>
+where
+    K: Clone + Eq + Hash,
+{
+    pub fn new() -> Self {
+        Self {
+            ids: HashMap::new(),
+            keys: Vec::new(),
+            sets: DisjointSet::new(0),
+        }
+    }
+
+    /// Adds `key` as a singleton set if it is not present and returns its id
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = self.sets.add();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        id
+    }
+
+    /// The dense id of `key`, None if it was never inserted
+    pub fn id(&self, key: &K) -> Option<usize> {
+        self.ids.get(key).copied()
+    }
+
+    /// The key with the given dense id
+    pub fn key(&self, id: usize) -> &K {
+        &self.keys[id]
+    }
+
+    /// Number of keys
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// The representative key of the set containing `key`, None if it was never inserted
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let id = self.id(key)?;
+        let root = self.sets.find(id);
+        Some(&self.keys[root])
+    }
+
+    /// Merges the sets containing `a` and `b`, inserting either if needed, false if they were
+    /// already the same set
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.sets.union(a, b)
+    }
+
+    /// False if either key was never inserted
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.id(a), self.id(b)) {
+            (Some(a), Some(b)) => self.sets.connected(a, b),
+            _ => false,
+        }
+    }
+
+    /// Number of disjoint sets
+    pub fn component_count(&self) -> usize {
+        self.sets.component_count()
+    }
+
+    /// Size of the set containing `key`, 0 if it was never inserted
+    pub fn component_size(&mut self, key: &K) -> usize {
+        self.id(key).map_or(0, |id| self.sets.component_size(id))
+    }
+
+    /// Sizes of all sets, largest first
+    pub fn component_sizes(&self) -> Vec<usize> {
+        self.sets.component_sizes()
+    }
+
+    /// The members of every set in insertion order, the sets ordered by their first inserted member
+    pub fn components(&mut self) -> Vec<Vec<&K>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|ids| ids.into_iter().map(|id| &self.keys[id]).collect())
+            .collect()
+    }
+}
+
+impl<K> Default for KeyedDisjointSet<K>
+where
+    K: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense_union_find() {
+        let mut sets = DisjointSet::new(8);
+        assert_eq!(sets.component_count(), 8);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.union(5, 6));
+
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 5));
+        assert_eq!(sets.component_count(), 4);
+        assert_eq!(sets.component_size(2), 4);
+        assert_eq!(sets.component_sizes(), vec![4, 2, 1, 1]);
+        assert_eq!(sets.components(), vec![vec![0, 1, 2, 3], vec![4], vec![5, 6], vec![7]]);
+
+        let id = sets.add();
+        assert_eq!(id, 8);
+        assert_eq!(sets.component_count(), 5);
+    }
+
+    #[test]
+    fn keyed_union_find() {
+        let mut sets = KeyedDisjointSet::new();
+        sets.union("a", "b");
+        sets.union("c", "d");
+        sets.insert("e");
+        sets.union("d", "b");
+
+        assert_eq!(sets.len(), 5);
+        assert_eq!(sets.component_count(), 2);
+        assert!(sets.connected(&"a", &"c"));
+        assert!(!sets.connected(&"a", &"e"));
+        assert!(!sets.connected(&"a", &"z"));
+        assert_eq!(sets.find(&"z"), None);
+        let root = *sets.find(&"a").unwrap();
+        assert_eq!(sets.find(&"d"), Some(&root));
+        assert_eq!(sets.component_size(&"b"), 4);
+        assert_eq!(sets.component_size(&"z"), 0);
+        assert_eq!(sets.components(), vec![vec![&"a", &"b", &"c", &"d"], vec![&"e"]]);
+        assert_eq!(sets.key(sets.id(&"c").unwrap()), &"c");
+    }
+
+    #[test]
+    fn constellations() {
+        let points: [[i32; 4]; 8] = [
+            [0, 0, 0, 0],
+            [3, 0, 0, 0],
+            [0, 3, 0, 0],
+            [0, 0, 3, 0],
+            [0, 0, 0, 3],
+            [0, 0, 0, 6],
+            [9, 0, 0, 0],
+            [12, 0, 0, 0],
+        ];
+        let mut sets = DisjointSet::new(points.len());
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                let distance = (0..4).map(|k| (points[i][k] - points[j][k]).abs()).sum::<i32>();
+                if distance <= 3 {
+                    sets.union(i, j);
+                }
+            }
+        }
+        assert_eq!(sets.component_count(), 2);
+        assert_eq!(sets.component_sizes(), vec![6, 2]);
+    }
+}
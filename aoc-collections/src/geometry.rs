@@ -0,0 +1,184 @@
+use aoc_math::number_theory::gcd;
+
+use crate::direction::Direction;
+use crate::point::Point2;
+
+/// Area and lattice point counts of a simple polygon with integer vertices
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolygonArea {
+    /// Twice the enclosed area, always an integer for lattice polygons
+    pub double_area: i64,
+    /// Lattice points on the edges
+    pub boundary: i64,
+}
+
+impl PolygonArea {
+    /// The enclosed area by the shoelace formula, rounded down when it is a half integer
+    pub fn area(&self) -> i64 {
+        self.double_area / 2
+    }
+
+    /// Lattice points strictly inside the polygon by Pick's theorem
+    pub fn interior(&self) -> i64 {
+        (self.double_area - self.boundary + 2) / 2
+    }
+
+    /// Lattice points inside or on the boundary, e.g. the cubes dug out for a lagoon
+    pub fn covered(&self) -> i64 {
+        self.interior() + self.boundary
+    }
+}
+
+/// Measures the polygon through `vertices` in order, the last vertex connects back to the first
+///
+/// Vertices may be given clockwise or anti-clockwise and collinear vertices are allowed, so every
+/// point of a walked path can be passed as is.
+pub fn polygon_area(vertices: &[Point2<i64>]) -> PolygonArea {
+    let mut double_area = 0;
+    let mut boundary = 0;
+    for (i, a) in vertices.iter().enumerate() {
+        let b = vertices[(i + 1) % vertices.len()];
+        double_area += a.x * b.y - b.x * a.y;
+        boundary += gcd(b.x - a.x, b.y - a.y);
+    }
+    PolygonArea {
+        double_area: double_area.abs(),
+        boundary,
+    }
+}
+
+/// The vertices visited by following `(direction, length)` instructions from the origin
+pub fn vertices_from_instructions<I>(instructions: I) -> Vec<Point2<i64>>
+where
+    I: IntoIterator<Item = (Direction, i64)>,
+{
+    let mut current = Point2::new(0, 0);
+    let mut vertices = vec![current];
+    for (direction, length) in instructions {
+        let [d_row, d_col] = direction.grid_offset();
+        current += Point2::new(d_col as i64, d_row as i64) * length;
+        vertices.push(current);
+    }
+    // A closed loop ends back at the origin, which is already the first vertex
+    if vertices.len() > 1 && vertices.last() == vertices.first() {
+        vertices.pop();
+    }
+    vertices
+}
+
+/// Measures the polygon traced by `(direction, length)` instructions such as a dig plan
+pub fn polygon_area_from_instructions<I>(instructions: I) -> PolygonArea
+where
+    I: IntoIterator<Item = (Direction, i64)>,
+{
+    polygon_area(&vertices_from_instructions(instructions))
+}
+
+/// Measures the loop walked through the `[row, col]` board positions in `path`, each a step from
+/// the previous one and the last a step from the first (the start may be repeated at the end)
+///
+/// `interior()` is the number of cells enclosed by the loop, e.g. the tiles inside a pipe loop.
+pub fn polygon_area_from_path(path: &[[usize; 2]]) -> PolygonArea {
+    let mut vertices = path
+        .iter()
+        .map(|&[row, col]| Point2::new(col as i64, row as i64))
+        .collect::<Vec<_>>();
+    if vertices.len() > 1 && vertices.last() == vertices.first() {
+        vertices.pop();
+    }
+    polygon_area(&vertices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triangle_with_pick() {
+        let vertices = [Point2::new(0, 0), Point2::new(4, 0), Point2::new(0, 3)];
+        let measure = polygon_area(&vertices);
+        assert_eq!(measure, PolygonArea { double_area: 12, boundary: 8 });
+        assert_eq!(measure.area(), 6);
+        assert_eq!(measure.interior(), 3);
+
+        let reversed = vertices.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(polygon_area(&reversed), measure);
+    }
+
+    const DIG_PLAN: [(char, i64, &str); 14] = [
+        ('R', 6, "70c710"),
+        ('D', 5, "0dc571"),
+        ('L', 2, "5713f0"),
+        ('D', 2, "d2c081"),
+        ('R', 2, "59c680"),
+        ('D', 2, "411b91"),
+        ('L', 5, "8ceee2"),
+        ('U', 2, "caa173"),
+        ('L', 1, "1b58a2"),
+        ('U', 2, "caa171"),
+        ('R', 2, "7807d2"),
+        ('U', 3, "a77fa3"),
+        ('L', 2, "015232"),
+        ('U', 2, "7a21e3"),
+    ];
+
+    #[test]
+    fn lagoon_dig_plan() {
+        let instructions = DIG_PLAN.iter().map(|&(d, len, _)| (Direction::try_from(d).unwrap(), len));
+        assert_eq!(polygon_area_from_instructions(instructions).covered(), 62);
+
+        let hex_instructions = DIG_PLAN.iter().map(|&(_, _, colour)| {
+            let length = i64::from_str_radix(&colour[..5], 16).unwrap();
+            (Direction::ORTHOGONAL[(colour.as_bytes()[5] - b'0' + 1) as usize % 4], length)
+        });
+        assert_eq!(polygon_area_from_instructions(hex_instructions).covered(), 952_408_144_115);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn pipe_loop_on_board() {
+        use aoc_generators::processors::board_generator::generate_2d_board_char;
+
+        let rows = [
+            "...........",
+            ".S-------7.",
+            ".|F-----7|.",
+            ".||.....||.",
+            ".||.....||.",
+            ".|L-7.F-J|.",
+            ".|..|.|..|.",
+            ".L--J.L--J.",
+            "...........",
+        ];
+        let board = generate_2d_board_char(&rows.join("\n"));
+
+        // Walk the loop from S, which connects east and south, by following each pipe
+        let connections = |c: char| match c {
+            '|' => [Direction::North, Direction::South],
+            '-' => [Direction::East, Direction::West],
+            'L' => [Direction::North, Direction::East],
+            'J' => [Direction::North, Direction::West],
+            '7' => [Direction::South, Direction::West],
+            'F' | 'S' => [Direction::South, Direction::East],
+            _ => panic!("Not a pipe: {}", c),
+        };
+        let start = [1, 1];
+        let mut path = vec![start];
+        let mut position = start;
+        let mut heading = Direction::East;
+        loop {
+            let [d_row, d_col] = heading.grid_offset();
+            position = [(position[0] as isize + d_row) as usize, (position[1] as isize + d_col) as usize];
+            if position == start {
+                break;
+            }
+            path.push(position);
+            let [a, b] = connections(board[position]);
+            heading = if a == heading.opposite() { b } else { a };
+        }
+
+        let measure = polygon_area_from_path(&path);
+        assert_eq!(measure.boundary as usize, path.len());
+        assert_eq!(measure.interior(), 4);
+    }
+}
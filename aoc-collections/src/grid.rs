@@ -0,0 +1,265 @@
+use crate::direction::Direction;
+use ndarray::{Array2, ArrayView2};
+use std::ops::{Index, IndexMut};
+
+/// Offsets (row, col) of the 4 orthogonal neighbours, clockwise from up
+pub const ORTHOGONAL_OFFSETS: [[isize; 2]; 4] = [[-1, 0], [0, 1], [1, 0], [0, -1]];
+
+/// Offsets (row, col) of all 8 neighbours, clockwise from up
+pub const ALL_OFFSETS: [[isize; 2]; 8] = [[-1, 0], [-1, 1], [0, 1], [1, 1], [1, 0], [1, -1], [0, -1], [-1, -1]];
+
+/// A 2D grid addressed by `[row, col]` positions
+///
+/// Wraps the `Array2` produced by `generate_2d_board_char` (or any other) and adds bounds checked
+/// neighbour lookups. When wrapping is enabled positions moving off one edge re-enter on the
+/// opposite edge (torus addressing).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+    wrapping: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn new(cells: Array2<T>) -> Self {
+        Self { cells, wrapping: false }
+    }
+
+    pub fn new_wrapping(cells: Array2<T>) -> Self {
+        Self { cells, wrapping: true }
+    }
+
+    pub fn set_wrapping(&mut self, wrapping: bool) {
+        self.wrapping = wrapping;
+    }
+
+    pub fn is_wrapping(&self) -> bool {
+        self.wrapping
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    pub fn contains(&self, pos: [usize; 2]) -> bool {
+        pos[0] < self.nrows() && pos[1] < self.ncols()
+    }
+
+    pub fn get(&self, pos: [usize; 2]) -> Option<&T> {
+        self.cells.get(pos)
+    }
+
+    pub fn get_mut(&mut self, pos: [usize; 2]) -> Option<&mut T> {
+        self.cells.get_mut(pos)
+    }
+
+    /// Applies a signed offset to a position
+    ///
+    /// Returns None if the result is off the grid, unless wrapping is enabled in which case the
+    /// position wraps around
+    pub fn offset_pos(&self, pos: [usize; 2], offset: [isize; 2]) -> Option<[usize; 2]> {
+        let (rows, cols) = (self.nrows() as isize, self.ncols() as isize);
+        if rows == 0 || cols == 0 {
+            return None;
+        }
+
+        let row = pos[0] as isize + offset[0];
+        let col = pos[1] as isize + offset[1];
+
+        if self.wrapping {
+            Some([row.rem_euclid(rows) as usize, col.rem_euclid(cols) as usize])
+        } else if (0..rows).contains(&row) && (0..cols).contains(&col) {
+            Some([row as usize, col as usize])
+        } else {
+            None
+        }
+    }
+
+    /// The position one step from `pos` in the given direction, see `offset_pos`
+    pub fn step(&self, pos: [usize; 2], direction: Direction) -> Option<[usize; 2]> {
+        self.offset_pos(pos, direction.grid_offset())
+    }
+
+    /// Gets the cell at a signed offset from a position, see `offset_pos`
+    pub fn get_offset(&self, pos: [usize; 2], offset: [isize; 2]) -> Option<&T> {
+        self.offset_pos(pos, offset).map(|p| &self.cells[p])
+    }
+
+    /// The positions and values of the cells at the given offsets from a position, cells off the
+    /// grid are skipped
+    pub fn neighbours_at<'a>(
+        &'a self,
+        pos: [usize; 2],
+        offsets: &'a [[isize; 2]],
+    ) -> impl Iterator<Item = ([usize; 2], &'a T)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.offset_pos(pos, offset))
+            .map(move |p| (p, &self.cells[p]))
+    }
+
+    /// The up to 4 orthogonal neighbours of a position
+    pub fn neighbours4(&self, pos: [usize; 2]) -> impl Iterator<Item = ([usize; 2], &T)> + '_ {
+        self.neighbours_at(pos, &ORTHOGONAL_OFFSETS)
+    }
+
+    /// The up to 8 orthogonal and diagonal neighbours of a position
+    pub fn neighbours8(&self, pos: [usize; 2]) -> impl Iterator<Item = ([usize; 2], &T)> + '_ {
+        self.neighbours_at(pos, &ALL_OFFSETS)
+    }
+
+    /// Iterates `(pos, &value)` pairs in row major order
+    pub fn iter(&self) -> impl Iterator<Item = ([usize; 2], &T)> + '_ {
+        self.cells.indexed_iter().map(|((r, c), v)| ([r, c], v))
+    }
+
+    /// Position of the first cell (row major) matching the predicate
+    pub fn position<P>(&self, mut predicate: P) -> Option<[usize; 2]>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().find(|(_, v)| predicate(v)).map(|(p, _)| p)
+    }
+
+    /// A view of the underlying array, e.g. for `GridVisualiser::draw_ref`
+    pub fn view(&self) -> ArrayView2<'_, T> {
+        self.cells.view()
+    }
+
+    pub fn as_array(&self) -> &Array2<T> {
+        &self.cells
+    }
+
+    pub fn as_array_mut(&mut self) -> &mut Array2<T> {
+        &mut self.cells
+    }
+
+    pub fn into_inner(self) -> Array2<T> {
+        self.cells
+    }
+
+    /// Maps every cell into a new grid with the same wrapping
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.map(f),
+            wrapping: self.wrapping,
+        }
+    }
+}
+
+impl<T> From<Array2<T>> for Grid<T> {
+    fn from(cells: Array2<T>) -> Self {
+        Self::new(cells)
+    }
+}
+
+impl<T> From<Grid<T>> for Array2<T> {
+    fn from(grid: Grid<T>) -> Self {
+        grid.cells
+    }
+}
+
+impl<T> Index<[usize; 2]> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: [usize; 2]) -> &Self::Output {
+        &self.cells[pos]
+    }
+}
+
+impl<T> IndexMut<[usize; 2]> for Grid<T> {
+    fn index_mut(&mut self, pos: [usize; 2]) -> &mut Self::Output {
+        &mut self.cells[pos]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn test_grid() -> Grid<char> {
+        Grid::new(array![['a', 'b', 'c'], ['d', 'e', 'f'], ['g', 'h', 'i']])
+    }
+
+    #[test]
+    fn neighbours4_centre_and_corner() {
+        let grid = test_grid();
+
+        let centre = grid.neighbours4([1, 1]).map(|(_, v)| *v).collect::<String>();
+        assert_eq!(centre, "bfhd");
+
+        let corner = grid.neighbours4([0, 0]).collect::<Vec<_>>();
+        assert_eq!(corner, vec![([0, 1], &'b'), ([1, 0], &'d')]);
+    }
+
+    #[test]
+    fn neighbours8_centre_and_edge() {
+        let grid = test_grid();
+
+        let centre = grid.neighbours8([1, 1]).map(|(_, v)| *v).collect::<String>();
+        assert_eq!(centre, "bcfihgda");
+
+        let edge = grid.neighbours8([2, 1]).map(|(_, v)| *v).collect::<String>();
+        assert_eq!(edge, "efigd");
+    }
+
+    #[test]
+    fn neighbours_wrapping() {
+        let mut grid = test_grid();
+        grid.set_wrapping(true);
+
+        let corner = grid.neighbours4([0, 0]).map(|(_, v)| *v).collect::<String>();
+        assert_eq!(corner, "gbdc");
+        assert_eq!(grid.neighbours8([0, 0]).count(), 8);
+    }
+
+    #[test]
+    fn get_offset_bounds() {
+        let grid = test_grid();
+        assert_eq!(grid.get_offset([1, 1], [1, 1]), Some(&'i'));
+        assert_eq!(grid.get_offset([1, 1], [-2, 0]), None);
+        assert_eq!(grid.get_offset([2, 2], [0, 1]), None);
+
+        let grid = Grid::new_wrapping(test_grid().into_inner());
+        assert_eq!(grid.get_offset([1, 1], [-2, 0]), Some(&'h'));
+        assert_eq!(grid.get_offset([2, 2], [4, 1]), Some(&'a'));
+    }
+
+    #[test]
+    fn step_in_direction() {
+        let grid = test_grid();
+        assert_eq!(grid.step([1, 1], Direction::NorthEast), Some([0, 2]));
+        assert_eq!(grid.step([0, 1], Direction::North), None);
+    }
+
+    #[test]
+    fn iter_and_position() {
+        let grid = test_grid();
+        let cells = grid.iter().collect::<Vec<_>>();
+        assert_eq!(cells.len(), 9);
+        assert_eq!(cells[5], ([1, 2], &'f'));
+        assert_eq!(grid.position(|c| *c == 'h'), Some([2, 1]));
+        assert_eq!(grid.position(|c| *c == 'z'), None);
+    }
+
+    #[test]
+    fn conversions_and_indexing() {
+        let array = array![[1, 2], [3, 4]];
+        let mut grid = Grid::from(array.clone());
+        assert_eq!(grid.view(), array.view());
+
+        grid[[0, 1]] = 5;
+        assert_eq!(grid[[0, 1]], 5);
+        assert_eq!(grid.map(|v| v * 2).into_inner(), array![[2, 10], [6, 8]]);
+
+        let back: Array2<i32> = grid.into();
+        assert_eq!(back, array![[1, 5], [3, 4]]);
+    }
+}
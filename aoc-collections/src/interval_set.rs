@@ -0,0 +1,323 @@
+use std::ops::{Range, RangeInclusive};
+
+use crate::numeric::Integer;
+
+/// A set of integers stored as sorted, disjoint, non-adjacent inclusive `(start, end)` bounds, so
+/// it can hold the maximum value of its type
+///
+/// Inserted ranges are merged with any ranges they overlap or touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>,
+}
+
+/// True if a range ending at `end` and one starting at `start` leave at least one value between them
+fn separated<T: Integer>(end: T, start: T) -> bool {
+    // `end < start` guarantees `end + 1` does not overflow
+    end < start && end + T::ONE < start
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: Vec::new() }
+    }
+
+    /// Adds the half-open `range`, empty ranges are ignored
+    pub fn insert(&mut self, range: Range<T>) {
+        if !range.is_empty() {
+            self.insert_bounds(range.start, range.end - T::ONE);
+        }
+    }
+
+    /// Adds the inclusive `range`, which may end at the type's maximum
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start <= end {
+            self.insert_bounds(start, end);
+        }
+    }
+
+    fn insert_bounds(&mut self, start: T, end: T) {
+        // Every interval in `first..last` overlaps or touches the new range
+        let first = self.intervals.partition_point(|&(_, e)| separated(e, start));
+        let last = self.intervals.partition_point(|&(s, _)| !separated(end, s));
+        let mut merged = (start, end);
+        if first < last {
+            merged.0 = merged.0.min(self.intervals[first].0);
+            merged.1 = merged.1.max(self.intervals[last - 1].1);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Removes every value in `range`, splitting intervals it falls inside
+    pub fn remove(&mut self, range: Range<T>) {
+        if !range.is_empty() {
+            self.remove_bounds(range.start, range.end - T::ONE);
+        }
+    }
+
+    /// Removes every value in the inclusive `range`
+    pub fn remove_inclusive(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start <= end {
+            self.remove_bounds(start, end);
+        }
+    }
+
+    fn remove_bounds(&mut self, start: T, end: T) {
+        let first = self.intervals.partition_point(|&(_, e)| e < start);
+        let last = self.intervals.partition_point(|&(s, _)| s <= end);
+        if first >= last {
+            return;
+        }
+
+        let mut remaining = Vec::new();
+        let (first_start, _) = self.intervals[first];
+        let (_, last_end) = self.intervals[last - 1];
+        if first_start < start {
+            remaining.push((first_start, start - T::ONE));
+        }
+        if end < last_end {
+            remaining.push((end + T::ONE, last_end));
+        }
+        self.intervals.splice(first..last, remaining);
+    }
+
+    /// Every value in either set
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for &(start, end) in &other.intervals {
+            result.insert_bounds(start, end);
+        }
+        result
+    }
+
+    /// Values in this set but not in `other`
+    pub fn subtract(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for &(start, end) in &other.intervals {
+            result.remove_bounds(start, end);
+        }
+        result
+    }
+
+    /// Values in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            let overlap = (a.0.max(b.0), a.1.min(b.1));
+            if overlap.0 <= overlap.1 {
+                intervals.push(overlap);
+            }
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Number of values covered, overflows if that does not fit in `T` (e.g. every `u8`)
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::ZERO, |acc, &(start, end)| acc + (end - start) + T::ONE)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of disjoint ranges
+    pub fn interval_count(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|&(_, e)| e < value);
+        self.intervals.get(index).is_some_and(|&(s, _)| s <= value)
+    }
+
+    /// True if every value of `range` is in the set
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let index = self.intervals.partition_point(|&(_, e)| e < range.start);
+        self.intervals.get(index).is_some_and(|&(s, e)| s <= range.start && range.end - T::ONE <= e)
+    }
+
+    /// True if any value of `range` is in the set
+    pub fn overlaps(&self, range: &Range<T>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+        let index = self.intervals.partition_point(|&(_, e)| e < range.start);
+        self.intervals.get(index).is_some_and(|&(s, _)| s < range.end)
+    }
+
+    /// The disjoint ranges in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// The smallest value in the set
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|&(start, _)| start)
+    }
+
+    /// The largest value in the set
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|&(_, end)| end)
+    }
+
+    /// Maps every value through a piecewise offset table of `(source range, destination start)`
+    /// entries, values outside every source range map to themselves
+    ///
+    /// Source ranges must not overlap. Intervals are split wherever they cross a source boundary.
+    pub fn map_ranges(&self, table: &[(Range<T>, T)]) -> Self {
+        let mut mapped = Self::new();
+        let mut unmapped = self.clone();
+        for (source, destination) in table {
+            let overlapping = self.intersection(&Self::from_iter([source.clone()]));
+            for &(start, end) in &overlapping.intervals {
+                mapped.insert_bounds(start - source.start + *destination, end - source.start + *destination);
+            }
+            unmapped.remove(source.clone());
+        }
+        mapped.union(&unmapped)
+    }
+}
+
+impl<T: Integer> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Integer> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert_inclusive(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i64>) -> Vec<Range<i64>> {
+        set.iter().map(|r| *r.start()..*r.end() + 1).collect()
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_adjacent() {
+        let mut set = IntervalSet::new();
+        set.insert(10..15);
+        set.insert(1..3);
+        set.insert(20..25);
+        assert_eq!(ranges(&set), vec![1..3, 10..15, 20..25]);
+
+        set.insert(3..5);
+        set.insert(14..21);
+        set.insert(7..7);
+        assert_eq!(ranges(&set), vec![1..5, 10..25]);
+        assert_eq!(set.len(), 19);
+        assert_eq!(set.interval_count(), 2);
+
+        set.insert_inclusive(5..=9);
+        assert_eq!(ranges(&set), vec![1..25]);
+    }
+
+    #[test]
+    fn fresh_ingredient_ranges() {
+        let set: IntervalSet<i64> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+        assert_eq!(ranges(&set), vec![3..6, 10..21]);
+        assert_eq!(set.len(), 14);
+        assert_eq!([1, 5, 8, 11, 17, 32].iter().filter(|&&id| set.contains(id)).count(), 3);
+        assert_eq!((set.min(), set.max()), (Some(3), Some(20)));
+    }
+
+    #[test]
+    fn remove_and_algebra() {
+        let mut set: IntervalSet<i64> = [0..10, 20..30].into_iter().collect();
+        set.remove(3..5);
+        set.remove(8..22);
+        assert_eq!(ranges(&set), vec![0..3, 5..8, 22..30]);
+
+        let other: IntervalSet<i64> = [2..6, 25..40].into_iter().collect();
+        assert_eq!(ranges(&set.intersection(&other)), vec![2..3, 5..6, 25..30]);
+        assert_eq!(ranges(&set.subtract(&other)), vec![0..2, 6..8, 22..25]);
+        assert_eq!(ranges(&set.union(&other)), vec![0..8, 22..40]);
+    }
+
+    #[test]
+    fn queries() {
+        let set: IntervalSet<u32> = [0..10, 20..30].into_iter().collect();
+        assert!(set.contains(0) && set.contains(9) && !set.contains(10) && set.contains(20));
+        assert!(set.contains_range(&(2..8)));
+        assert!(!set.contains_range(&(8..22)));
+        assert!(set.overlaps(&(8..22)));
+        assert!(!set.overlaps(&(10..20)));
+        assert!(IntervalSet::<u32>::new().is_empty());
+    }
+
+    #[test]
+    fn bounds_at_type_limits() {
+        let mut set = IntervalSet::<u8>::new();
+        set.insert_inclusive(250..=255);
+        set.insert_inclusive(240..=249);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![240..=255]);
+        assert_eq!((set.max(), set.len()), (Some(255), 16));
+        assert!(set.contains(255));
+
+        set.remove_inclusive(255..=255);
+        assert_eq!(set.max(), Some(254));
+
+        let mut signed: IntervalSet<i8> = [i8::MIN..=-100, 100..=i8::MAX].into_iter().collect();
+        signed.remove(-128..-127);
+        assert_eq!(signed.iter().collect::<Vec<_>>(), vec![-127..=-100, 100..=127]);
+        assert!(signed.overlaps(&(120..127)) && !signed.contains(-128));
+    }
+
+    const ALMANAC: [&[(i64, i64, i64)]; 7] = [
+        &[(50, 98, 2), (52, 50, 48)],
+        &[(0, 15, 37), (37, 52, 2), (39, 0, 15)],
+        &[(49, 53, 8), (0, 11, 42), (42, 0, 7), (57, 7, 4)],
+        &[(88, 18, 7), (18, 25, 70)],
+        &[(45, 77, 23), (81, 45, 19), (68, 64, 13)],
+        &[(0, 69, 1), (1, 0, 69)],
+        &[(60, 56, 37), (56, 93, 4)],
+    ];
+
+    #[test]
+    fn seed_to_location_mapping() {
+        let seeds: IntervalSet<i64> = [79..79 + 14, 55..55 + 13].into_iter().collect();
+        let locations = ALMANAC.iter().fold(seeds.clone(), |set, entries| {
+            let table = entries.iter().map(|&(dest, src, len)| (src..src + len, dest)).collect::<Vec<_>>();
+            set.map_ranges(&table)
+        });
+        assert_eq!(locations.min(), Some(46));
+        assert_eq!(locations.len(), seeds.len());
+    }
+}
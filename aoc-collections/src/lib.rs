@@ -1,9 +1,25 @@
+pub mod combinatorics;
+pub mod counter;
+pub mod direction;
+pub mod disjoint_set;
+pub mod geometry;
+#[cfg(feature = "ndarray")]
+pub mod grid;
+pub mod interval_set;
+pub mod memo;
+pub mod numeric;
+pub mod ordering;
+pub mod point;
+#[cfg(feature = "ndarray")]
+pub mod region;
 pub mod search;
+pub mod simulation;
 
 use std::collections::HashMap;
 
-pub fn count_elements<T>(collection: &Vec<T>) -> HashMap<&T, i32> where T: std::hash::Hash + std::cmp::Eq {
-    collection.into_iter().fold(HashMap::new(), |mut acc, x| {
+/// Counts of each element in a slice, see `counter::Counter` for weighted counts and arithmetic
+pub fn count_elements<T>(collection: &[T]) -> HashMap<&T, i32> where T: std::hash::Hash + std::cmp::Eq {
+    collection.iter().fold(HashMap::new(), |mut acc, x| {
         *acc.entry(x).or_insert(0) += 1;
         acc
     })
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// How often a memoised function found its answer in the cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    /// Results currently held in the cache
+    pub entries: usize,
+}
+
+/// The handle a memoised function recurses through, answering from the cache when it can
+pub struct Recurse<'a, K, V> {
+    cache: &'a mut HashMap<K, V>,
+    stats: &'a mut CacheStats,
+    function: &'a dyn Fn(&mut Recurse<K, V>, K) -> V,
+}
+
+impl<K, V> Recurse<'_, K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// The result for `key`, computing and caching it on the first call
+    pub fn call(&mut self, key: K) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let function = self.function;
+        let value = function(self, key.clone());
+        self.cache.insert(key, value.clone());
+        self.stats.entries = self.cache.len();
+        value
+    }
+}
+
+/// A recursive function whose results are cached by argument, e.g. counting the ways to build a
+/// design from towel patterns
+///
+/// The function receives a `Recurse` handle to call itself through instead of calling itself
+/// directly, so every recursive call shares the cache. Multiple arguments are passed as a tuple.
+pub struct Memoised<K, V, F> {
+    cache: HashMap<K, V>,
+    stats: CacheStats,
+    function: F,
+}
+
+impl<K, V, F> Memoised<K, V, F>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut Recurse<K, V>, K) -> V,
+{
+    pub fn new(function: F) -> Self {
+        Self {
+            cache: HashMap::new(),
+            stats: CacheStats::default(),
+            function,
+        }
+    }
+
+    /// The result for `key`, reusing every result cached by earlier calls
+    pub fn get(&mut self, key: K) -> V {
+        let mut recurse = Recurse {
+            cache: &mut self.cache,
+            stats: &mut self.stats,
+            function: &self.function,
+        };
+        recurse.call(key)
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Forgets every cached result and resets the statistics, e.g. between puzzle parts whose
+    /// answers differ for the same arguments
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.stats = CacheStats::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fibonacci_stats() {
+        let mut fibonacci = Memoised::new(|r, n: u64| if n < 2 { n } else { r.call(n - 1) + r.call(n - 2) });
+        assert_eq!(fibonacci.get(90), 2_880_067_194_370_816_120);
+        assert_eq!(fibonacci.stats(), CacheStats { hits: 88, misses: 91, entries: 91 });
+
+        assert_eq!(fibonacci.get(50), 12_586_269_025);
+        assert_eq!(fibonacci.stats().hits, 89);
+
+        fibonacci.clear();
+        assert_eq!(fibonacci.stats(), CacheStats::default());
+        assert_eq!(fibonacci.get(10), 55);
+        assert_eq!(fibonacci.stats().entries, 11);
+    }
+
+    #[test]
+    fn blinking_stones() {
+        let mut stones = Memoised::new(|r, (stone, blinks): (u64, u32)| -> u64 {
+            if blinks == 0 {
+                return 1;
+            }
+            let digits = stone.checked_ilog10().unwrap_or(0) + 1;
+            if stone == 0 {
+                r.call((1, blinks - 1))
+            } else if digits.is_multiple_of(2) {
+                let split = 10u64.pow(digits / 2);
+                r.call((stone / split, blinks - 1)) + r.call((stone % split, blinks - 1))
+            } else {
+                r.call((stone * 2024, blinks - 1))
+            }
+        });
+        assert_eq!([125, 17].iter().map(|&s| stones.get((s, 6))).sum::<u64>(), 22);
+        assert_eq!([125, 17].iter().map(|&s| stones.get((s, 25))).sum::<u64>(), 55312);
+        assert!(stones.stats().hits > 0);
+    }
+
+    #[test]
+    fn towel_arrangements() {
+        let patterns = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+        let designs = ["brwrr", "bggr", "gbbr", "rrbgbr", "ubwu", "bwurrg", "brgr", "bbrgwb"];
+        let mut ways = Memoised::new(|r, design: &str| -> u64 {
+            if design.is_empty() {
+                return 1;
+            }
+            patterns
+                .iter()
+                .filter_map(|p| design.strip_prefix(p))
+                .map(|rest| r.call(rest))
+                .sum()
+        });
+        let counts = designs.iter().map(|d| ways.get(d)).collect::<Vec<_>>();
+        assert_eq!(counts.iter().filter(|&&c| c > 0).count(), 6);
+        assert_eq!(counts.iter().sum::<u64>(), 16);
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, BitAnd, BitXor, Mul, Shr, Sub, SubAssign};
+
+/// Integer and floating point types, e.g. values that can be averaged
+pub trait Number: Copy + Debug + Default + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    fn to_f64(self) -> f64;
+}
+
+/// Primitive integer types, shared by point coordinates, counts, interval bounds and binary search
+pub trait Integer:
+    Number
+    + Eq
+    + Ord
+    + Hash
+    + AddAssign
+    + SubAssign
+    + BitAnd<Output = Self>
+    + BitXor<Output = Self>
+    + Shr<u32, Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// Adds a signed delta, None if the result does not fit in the type (e.g. below zero for unsigned)
+    fn checked_add_delta(self, delta: isize) -> Option<Self>;
+    fn to_usize(self) -> Option<usize>;
+    fn from_usize(value: usize) -> Option<Self>;
+
+    /// Absolute difference between two values
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    /// The average of two values rounded down, without overflowing
+    fn floor_midpoint(self, other: Self) -> Self {
+        // Shared bits plus half of the differing ones, the shift is arithmetic for signed types
+        (self & other) + ((self ^ other) >> 1)
+    }
+
+    /// Subtraction clamped at zero
+    fn clamped_sub(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            Self::ZERO
+        }
+    }
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_add_delta(self, delta: isize) -> Option<Self> {
+                    match i128::try_from(self) {
+                        // Every value but a large u128 fits, as does any delta
+                        Ok(value) => <$t>::try_from(value.checked_add(delta as i128)?).ok(),
+                        Err(_) => {
+                            let magnitude = <$t>::try_from(delta.unsigned_abs()).ok()?;
+                            if delta < 0 {
+                                self.checked_sub(magnitude)
+                            } else {
+                                <$t>::checked_add(self, magnitude)
+                            }
+                        }
+                    }
+                }
+
+                fn to_usize(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+
+                fn from_usize(value: usize) -> Option<Self> {
+                    <$t>::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_add_delta_bounds() {
+        assert_eq!(5u8.checked_add_delta(-5), Some(0));
+        assert_eq!(5u8.checked_add_delta(-6), None);
+        assert_eq!(250u8.checked_add_delta(6), None);
+        assert_eq!(0i64.checked_add_delta(isize::MIN), Some(isize::MIN as i64));
+        assert_eq!((-100i8).checked_add_delta(200), Some(100));
+        assert_eq!(u128::MAX.checked_add_delta(-1), Some(u128::MAX - 1));
+        assert_eq!(u128::MAX.checked_add_delta(1), None);
+    }
+
+    #[test]
+    fn distance_and_clamped_sub() {
+        assert_eq!(3u32.distance(10), 7);
+        assert_eq!((-4i32).distance(3), 7);
+        assert_eq!(3u32.clamped_sub(10), 0);
+        assert_eq!(10i64.clamped_sub(3), 7);
+    }
+
+    #[test]
+    fn floor_midpoints() {
+        assert_eq!(3u8.floor_midpoint(8), 5);
+        assert_eq!(u8::MAX.floor_midpoint(u8::MAX - 1), u8::MAX - 1);
+        assert_eq!((-3i32).floor_midpoint(-2), -3);
+        assert_eq!((-7i64).floor_midpoint(4), -2);
+        assert_eq!(i128::MIN.floor_midpoint(i128::MAX), -1);
+        assert_eq!(u128::MAX.floor_midpoint(u128::MAX), u128::MAX);
+    }
+}
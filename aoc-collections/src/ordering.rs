@@ -0,0 +1,250 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// Topological sort with Kahn's algorithm, `(a, b)` edges meaning `a` comes before `b`
+///
+/// Whenever several nodes are ready the smallest according to `compare` is taken, so the order is
+/// deterministic. Nodes only appearing in `edges` are included. On a cycle the error holds the
+/// nodes that lie on (or between) cycles, ordered by `compare`.
+pub fn topological_sort<N, I, E, C>(nodes: I, edges: E, mut compare: C) -> Result<Vec<N>, Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    E: IntoIterator<Item = (N, N)>,
+    C: FnMut(&N, &N) -> Ordering,
+{
+    let mut all_nodes = Vec::new();
+    let mut in_degree: HashMap<N, usize> = HashMap::new();
+    let mut successors: HashMap<N, Vec<N>> = HashMap::new();
+    let add_node = |node: &N, all_nodes: &mut Vec<N>, in_degree: &mut HashMap<N, usize>| {
+        if !in_degree.contains_key(node) {
+            in_degree.insert(node.clone(), 0);
+            all_nodes.push(node.clone());
+        }
+    };
+
+    for node in nodes {
+        add_node(&node, &mut all_nodes, &mut in_degree);
+    }
+    let mut seen_edges = HashSet::new();
+    for (before, after) in edges {
+        add_node(&before, &mut all_nodes, &mut in_degree);
+        add_node(&after, &mut all_nodes, &mut in_degree);
+        if seen_edges.insert((before.clone(), after.clone())) {
+            *in_degree.get_mut(&after).unwrap() += 1;
+            successors.entry(before).or_default().push(after);
+        }
+    }
+
+    let mut ready = all_nodes.iter().filter(|n| in_degree[*n] == 0).cloned().collect::<Vec<_>>();
+    let mut order = Vec::with_capacity(all_nodes.len());
+    while !ready.is_empty() {
+        let next_index = (0..ready.len())
+            .min_by(|&a, &b| compare(&ready[a], &ready[b]))
+            .unwrap();
+        let node = ready.swap_remove(next_index);
+        for after in successors.get(&node).into_iter().flatten() {
+            let degree = in_degree.get_mut(after).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                ready.push(after.clone());
+            }
+        }
+        order.push(node);
+    }
+
+    if order.len() == all_nodes.len() {
+        return Ok(order);
+    }
+
+    // Every unsorted node has an unsorted predecessor, trimming those that lead nowhere leaves the cycles
+    let placed = order.into_iter().collect::<HashSet<_>>();
+    let mut remaining = all_nodes.into_iter().filter(|n| !placed.contains(n)).collect::<HashSet<_>>();
+    loop {
+        let dead_ends = remaining
+            .iter()
+            .filter(|n| !successors.get(*n).into_iter().flatten().any(|s| remaining.contains(s)))
+            .cloned()
+            .collect::<Vec<_>>();
+        if dead_ends.is_empty() {
+            break;
+        }
+        for node in dead_ends {
+            remaining.remove(&node);
+        }
+    }
+    let mut cycle = remaining.into_iter().collect::<Vec<_>>();
+    cycle.sort_by(&mut compare);
+    Err(cycle)
+}
+
+/// Precedence rules where `(a, b)` means `a` must come before `b`, such as the `a|b` page ordering
+/// rules
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrecedenceRules<N>
+where
+    N: Eq + Hash,
+{
+    rules: HashSet<(N, N)>,
+}
+
+impl<N> PrecedenceRules<N>
+where
+    N: Clone + Eq + Hash,
+{
+    pub fn new() -> Self {
+        Self { rules: HashSet::new() }
+    }
+
+    /// Requires `before` to come before `after`
+    pub fn add(&mut self, before: N, after: N) {
+        self.rules.insert((before, after));
+    }
+
+    /// Parses one `before<separator>after` rule per line, empty lines are skipped
+    pub fn parse(input: &str, separator: char) -> Result<Self, String>
+    where
+        N: FromStr,
+    {
+        let mut rules = Self::new();
+        for line in input.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let (before, after) = line
+                .split_once(separator)
+                .ok_or_else(|| format!("Invalid rule: {}", line))?;
+            let before = before.trim().parse().map_err(|_| format!("Invalid rule: {}", line))?;
+            let after = after.trim().parse().map_err(|_| format!("Invalid rule: {}", line))?;
+            rules.add(before, after);
+        }
+        Ok(rules)
+    }
+
+    /// Less if a rule puts `a` before `b`, Greater if one puts `b` before `a`, otherwise Equal
+    pub fn compare(&self, a: &N, b: &N) -> Ordering {
+        if self.rules.contains(&(a.clone(), b.clone())) {
+            Ordering::Less
+        } else if self.rules.contains(&(b.clone(), a.clone())) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// `compare` as a closure, e.g. for `is_sorted_by`
+    pub fn comparator(&self) -> impl Fn(&N, &N) -> Ordering + '_ {
+        move |a, b| self.compare(a, b)
+    }
+
+    /// True if no rule is broken by any pair of elements in `sequence`
+    pub fn is_ordered(&self, sequence: &[N]) -> bool {
+        sequence
+            .iter()
+            .enumerate()
+            .all(|(i, later)| sequence[..i].iter().all(|earlier| self.compare(earlier, later) != Ordering::Greater))
+    }
+
+    /// `sequence` reordered to satisfy every rule between its elements, keeping the original order
+    /// where the rules do not decide, Err with the elements on a cycle if no such order exists
+    ///
+    /// Repeated elements are kept, every copy follows the rules of its value.
+    pub fn sort(&self, sequence: &[N]) -> Result<Vec<N>, Vec<N>> {
+        // Sort positions rather than values so repeated elements stay distinct nodes
+        let mut positions: HashMap<&N, Vec<usize>> = HashMap::new();
+        for (i, element) in sequence.iter().enumerate() {
+            positions.entry(element).or_default().push(i);
+        }
+        let mut edges = Vec::new();
+        for (before, after) in &self.rules {
+            if let (Some(befores), Some(afters)) = (positions.get(before), positions.get(after)) {
+                edges.extend(befores.iter().flat_map(|&b| afters.iter().map(move |&a| (b, a))));
+            }
+        }
+        let to_elements = |indices: Vec<usize>| indices.into_iter().map(|i| sequence[i].clone()).collect();
+        topological_sort(0..sequence.len(), edges, |a, b| a.cmp(b))
+            .map(to_elements)
+            .map_err(to_elements)
+    }
+}
+
+impl<N> FromIterator<(N, N)> for PrecedenceRules<N>
+where
+    N: Clone + Eq + Hash,
+{
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(iter: I) -> Self {
+        Self {
+            rules: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::{find_middle_element, FindMiddleElementMode};
+
+    #[test]
+    fn sort_with_tie_break() {
+        let edges = [('C', 'A'), ('C', 'F'), ('A', 'B'), ('A', 'D'), ('B', 'E'), ('D', 'E'), ('F', 'E')];
+        let order = topological_sort([], edges, |a, b| a.cmp(b)).unwrap();
+        assert_eq!(order.into_iter().collect::<String>(), "CABDFE");
+
+        let reversed = topological_sort([], edges, |a, b| b.cmp(a)).unwrap();
+        assert_eq!(reversed.into_iter().collect::<String>(), "CFADBE");
+    }
+
+    #[test]
+    fn isolated_nodes_included() {
+        let order = topological_sort([5, 1, 3], [(3, 2)], |a, b| a.cmp(b)).unwrap();
+        assert_eq!(order, vec![1, 3, 2, 5]);
+    }
+
+    #[test]
+    fn cycle_reported() {
+        // 2 -> 3 -> 4 -> 2 is a cycle, 5 only follows it and 1 is fine
+        let edges = [(1, 2), (2, 3), (3, 4), (4, 2), (4, 5)];
+        assert_eq!(topological_sort([], edges, |a, b| a.cmp(b)), Err(vec![2, 3, 4]));
+    }
+
+    const RULES: &str = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n61|53\n\
+        97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13";
+
+    const UPDATES: [&[u32]; 6] = [
+        &[75, 47, 61, 53, 29],
+        &[97, 61, 53, 29, 13],
+        &[75, 29, 13],
+        &[75, 97, 47, 61, 53],
+        &[61, 13, 29],
+        &[97, 13, 75, 29, 47],
+    ];
+
+    #[test]
+    fn page_ordering() {
+        let rules = PrecedenceRules::<u32>::parse(RULES, '|').unwrap();
+        let middle = |pages: &[u32]| *find_middle_element(pages, FindMiddleElementMode::Error).unwrap();
+
+        let (ordered, unordered): (Vec<&[u32]>, Vec<&[u32]>) = UPDATES.iter().partition(|u| rules.is_ordered(u));
+        assert_eq!(ordered.iter().map(|u| middle(u)).sum::<u32>(), 143);
+        assert!(ordered.iter().all(|u| u.is_sorted_by(|a, b| rules.comparator()(a, b) != Ordering::Greater)));
+
+        let fixed = unordered.iter().map(|u| rules.sort(u).unwrap()).collect::<Vec<_>>();
+        assert_eq!(fixed[0], vec![97, 75, 47, 61, 53]);
+        assert_eq!(fixed.iter().map(|u| middle(u)).sum::<u32>(), 123);
+    }
+
+    #[test]
+    fn sort_keeps_repeated_elements() {
+        let rules: PrecedenceRules<u32> = [(1, 2), (2, 3), (1, 3)].into_iter().collect();
+        assert_eq!(rules.sort(&[2, 1, 2, 3]), Ok(vec![1, 2, 2, 3]));
+        assert_eq!(rules.sort(&[3, 3, 1]), Ok(vec![1, 3, 3]));
+
+        let cyclic: PrecedenceRules<u32> = [(1, 2), (2, 1)].into_iter().collect();
+        assert_eq!(cyclic.sort(&[2, 1, 2]), Err(vec![2, 1, 2]));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(PrecedenceRules::<u32>::parse("1|2\n3-4", '|'), Err("Invalid rule: 3-4".to_string()));
+        assert_eq!(PrecedenceRules::<u32>::parse("1|x", '|'), Err("Invalid rule: 1|x".to_string()));
+    }
+}
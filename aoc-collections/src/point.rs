@@ -0,0 +1,222 @@
+use crate::direction::Direction;
+use crate::numeric::Integer;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+/// A 2D point, `x` is the column and `y` the row (so `y` grows downwards as it does in a grid)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Integer> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    /// The point one step away in the given direction, None if a coordinate leaves the type's range
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        let [d_row, d_col] = direction.grid_offset();
+        Some(Self::new(self.x.checked_add_delta(d_col)?, self.y.checked_add_delta(d_row)?))
+    }
+
+    /// The `[row, col]` ndarray index of this point, None if either coordinate is negative
+    pub fn to_index(&self) -> Option<[usize; 2]> {
+        Some([self.y.to_usize()?, self.x.to_usize()?])
+    }
+
+    /// The point at an ndarray `[row, col]` index, None if it does not fit in the coordinate type
+    pub fn from_index(index: [usize; 2]) -> Option<Self> {
+        Some(Self::new(T::from_usize(index[1])?, T::from_usize(index[0])?))
+    }
+}
+
+impl<T: Integer> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Integer> AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Integer> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Integer> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Integer> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+/// A 3D point
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Integer> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y)).max(self.z.distance(other.z))
+    }
+}
+
+impl<T: Integer> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Integer> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Integer> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Integer> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Integer> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::count_elements;
+
+    #[test]
+    fn point2_arithmetic() {
+        let a = Point2::new(3, -2);
+        let b = Point2::new(1, 4);
+        assert_eq!(a + b, Point2::new(4, 2));
+        assert_eq!(a - b, Point2::new(2, -6));
+        assert_eq!(a * 3, Point2::new(9, -6));
+
+        let mut c = a;
+        c += b;
+        c -= Point2::new(1, 1);
+        assert_eq!(c, Point2::new(3, 1));
+    }
+
+    #[test]
+    fn point2_distances() {
+        let a = Point2::new(1i64, 1);
+        let b = Point2::new(4i64, -3);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+
+        let a = Point2::new(5u8, 2);
+        let b = Point2::new(2u8, 9);
+        assert_eq!(a.manhattan(&b), 10);
+        assert_eq!(b.manhattan(&a), 10);
+    }
+
+    #[test]
+    fn point2_index_conversion() {
+        let p = Point2::new(3i32, 1);
+        assert_eq!(p.to_index(), Some([1, 3]));
+        assert_eq!(Point2::new(-1i32, 1).to_index(), None);
+        assert_eq!(Point2::<i32>::from_index([1, 3]), Some(p));
+        assert_eq!(Point2::<u8>::from_index([0, 300]), None);
+    }
+
+    #[test]
+    fn point2_step() {
+        let p = Point2::new(0usize, 0);
+        assert_eq!(p.step(Direction::East), Some(Point2::new(1, 0)));
+        assert_eq!(p.step(Direction::South), Some(Point2::new(0, 1)));
+        assert_eq!(p.step(Direction::North), None);
+
+        let p = Point2::new(0i32, 0);
+        assert_eq!(p.step(Direction::NorthWest), Some(Point2::new(-1, -1)));
+    }
+
+    #[test]
+    fn point3_arithmetic_and_distances() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::from((4, -2, 3));
+        assert_eq!(a + b, Point3::new(5, 0, 6));
+        assert_eq!(a - b, Point3::new(-3, 4, 0));
+        assert_eq!(a * 2, Point3::new(2, 4, 6));
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+    }
+
+    #[test]
+    fn points_as_count_keys() {
+        let visited = vec![Point2::new(0, 0), Point2::new(1, 0), Point2::new(0, 0)];
+        let counts = count_elements(&visited);
+        assert_eq!(counts[&Point2::new(0, 0)], 2);
+        assert_eq!(counts[&Point2::new(1, 0)], 1);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::direction::Direction;
+use crate::grid::Grid;
+use ndarray::{Array2, ArrayBase, Data, Ix2};
+
+/// A connected group of orthogonally adjacent cells sharing the same key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<K> {
+    /// Index of the region in `Regions::regions` and its value in `Regions::labels`
+    pub label: usize,
+    pub key: K,
+    /// Cells in the order the flood fill reached them, starting with the top-left most cell
+    pub cells: Vec<[usize; 2]>,
+    /// Cell edges that border another region or the edge of the board
+    pub perimeter: usize,
+    /// Straight runs of fence around the region (outside and any holes), counted via its corners
+    pub sides: usize,
+}
+
+impl<K> Region<K> {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// Every region of a board and the label of the region each cell belongs to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions<K> {
+    /// Regions ordered by their first cell in row-major order
+    pub regions: Vec<Region<K>>,
+    pub labels: Array2<usize>,
+}
+
+impl<K> Regions<K> {
+    /// The region a cell belongs to
+    pub fn region_at(&self, pos: [usize; 2]) -> &Region<K> {
+        &self.regions[self.labels[pos]]
+    }
+}
+
+/// Flood fills orthogonally connected cells with equal values into labelled regions
+pub fn find_regions<S, T>(board: &ArrayBase<S, Ix2>) -> Regions<T>
+where
+    S: Data<Elem = T>,
+    T: Clone + PartialEq,
+{
+    find_regions_by_key(board, |value| value.clone())
+}
+
+/// Flood fills orthogonally connected cells whose keys are equal into labelled regions, e.g.
+/// `|&h| h != 9` to find basins bounded by height 9 cells
+pub fn find_regions_by_key<S, T, K, F>(board: &ArrayBase<S, Ix2>, mut key: F) -> Regions<K>
+where
+    S: Data<Elem = T>,
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+    let keys = Grid::new(board.map(&mut key));
+    let mut labels = Array2::from_elem(board.raw_dim(), usize::MAX);
+    let mut regions = Vec::new();
+
+    for (start, region_key) in keys.iter() {
+        if labels[start] != usize::MAX {
+            continue;
+        }
+
+        let label = regions.len();
+        labels[start] = label;
+        let mut cells = vec![start];
+        let mut next = 0;
+        while next < cells.len() {
+            let pos = cells[next];
+            next += 1;
+            for direction in Direction::ORTHOGONAL {
+                if let Some(neighbour) = keys.step(pos, direction) {
+                    if labels[neighbour] == usize::MAX && keys[neighbour] == *region_key {
+                        labels[neighbour] = label;
+                        cells.push(neighbour);
+                    }
+                }
+            }
+        }
+
+        regions.push(Region {
+            label,
+            key: key(&board[start]),
+            cells,
+            perimeter: 0,
+            sides: 0,
+        });
+    }
+
+    for region in regions.iter_mut() {
+        let label = region.label;
+        let inside = |pos: Option<[usize; 2]>| pos.is_some_and(|p| labels[p] == label);
+        for &pos in &region.cells {
+            region.perimeter += Direction::ORTHOGONAL
+                .iter()
+                .filter(|&&d| !inside(keys.step(pos, d)))
+                .count();
+
+            // Each corner of the region's outline is one end of a side
+            for direction in Direction::ORTHOGONAL {
+                let clockwise = direction.turn_right();
+                let a = inside(keys.step(pos, direction));
+                let b = inside(keys.step(pos, clockwise));
+                let diagonal = inside(keys.step(pos, direction.turn_right_45()));
+                if (!a && !b) || (a && b && !diagonal) {
+                    region.sides += 1;
+                }
+            }
+        }
+    }
+
+    Regions { regions, labels }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_generators::processors::board_generator::generate_2d_board_char;
+    use ndarray::array;
+
+    fn board(rows: &[&str]) -> Array2<char> {
+        generate_2d_board_char(&rows.join("\n"))
+    }
+
+    fn prices(regions: &Regions<char>) -> (usize, usize) {
+        regions.regions.iter().fold((0, 0), |(perimeter, sides), r| {
+            (perimeter + r.area() * r.perimeter, sides + r.area() * r.sides)
+        })
+    }
+
+    #[test]
+    fn small_garden() {
+        let garden = board(&["AAAA", "BBCD", "BBCC", "EEEC"]);
+        let regions = find_regions(&garden);
+        assert_eq!(regions.regions.len(), 5);
+
+        let c = regions.region_at([1, 2]);
+        assert_eq!((c.key, c.area(), c.perimeter, c.sides), ('C', 4, 10, 8));
+        assert_eq!(regions.labels, array![[0, 0, 0, 0], [1, 1, 2, 3], [1, 1, 2, 2], [4, 4, 4, 2]]);
+        assert_eq!(prices(&regions), (140, 80));
+    }
+
+    #[test]
+    fn enclosed_regions() {
+        let garden = board(&["OOOOO", "OXOXO", "OOOOO", "OXOXO", "OOOOO"]);
+        let regions = find_regions(&garden);
+        assert_eq!(regions.regions.len(), 5);
+        assert_eq!(regions.regions[0].perimeter, 36);
+        assert_eq!(regions.regions[0].sides, 20);
+        assert_eq!(prices(&regions), (772, 436));
+    }
+
+    #[test]
+    fn larger_garden() {
+        let garden = board(&[
+            "RRRRIICCFF",
+            "RRRRIICCCF",
+            "VVRRRCCFFF",
+            "VVRCCCJFFF",
+            "VVVVCJJCFE",
+            "VVIVCCJJEE",
+            "VVIIICJJEE",
+            "MIIIIIJJEE",
+            "MIIISIJEEE",
+            "MMMISSJEEE",
+        ]);
+        let regions = find_regions(&garden);
+        assert_eq!(regions.regions.len(), 11);
+        assert_eq!(prices(&regions), (1930, 1206));
+    }
+
+    #[test]
+    fn basins_by_key() {
+        let heights = array![
+            [2, 1, 9, 9, 9, 4, 3, 2, 1, 0],
+            [3, 9, 8, 7, 8, 9, 4, 9, 2, 1],
+            [9, 8, 5, 6, 7, 8, 9, 8, 9, 2],
+            [8, 7, 6, 7, 8, 9, 6, 7, 8, 9],
+            [9, 8, 9, 9, 9, 6, 5, 6, 7, 8],
+        ];
+        let regions = find_regions_by_key(&heights, |&h| h != 9);
+        let mut sizes = regions.regions.iter().filter(|r| r.key).map(Region::area).collect::<Vec<_>>();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        assert_eq!(sizes, vec![14, 9, 9, 3]);
+        assert_eq!(sizes[..3].iter().product::<usize>(), 1134);
+    }
+}
@@ -1,5 +1,13 @@
+pub mod bisect;
+#[cfg(feature = "ndarray")]
+pub mod ray;
+pub mod selection;
+pub mod shortest_path;
+pub mod traversal;
 
 /// Defines operation if length of collection is divisible by 2 (i.e. cannot determine exact middle element)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FindMiddleElementMode {
     /// find_middle_element will return as Err
     Error,
@@ -9,38 +17,101 @@ pub enum FindMiddleElementMode {
     Right,
 }
 
-pub fn find_middle_element<T>(collection: &Vec<T>, mode: FindMiddleElementMode) -> Result<&T, &str>
-where T: std::hash::Hash + std::cmp::Eq {
-    let midpoint = collection.len() / 2;
-    if collection.len() % 2 == 0 {
+/// The element at the middle position of the collection, see `selection::median` for the middle by value
+pub fn find_middle_element<T>(collection: &[T], mode: FindMiddleElementMode) -> Result<&T, &str> {
+    middle_index(collection.len(), mode).map(|index| &collection[index])
+}
+
+/// The index of the middle element of `len` elements, shared by every middle element lookup
+pub(crate) fn middle_index(len: usize, mode: FindMiddleElementMode) -> Result<usize, &'static str> {
+    if len == 0 {
+        return Err("Collection is empty");
+    }
+    let midpoint = len / 2;
+    if len.is_multiple_of(2) {
         match mode {
             FindMiddleElementMode::Error => Err("Collection has even number of elements and error mode used"),
-            FindMiddleElementMode::Left => Ok(&collection[midpoint - 1]),
-            FindMiddleElementMode::Right => Ok(&collection[midpoint]),
+            FindMiddleElementMode::Left => Ok(midpoint - 1),
+            FindMiddleElementMode::Right => Ok(midpoint),
         }
     } else {
-        Ok(&collection[midpoint])
+        Ok(midpoint)
     }
 }
 
 /// FindMode defines if the next element should be found ascending or descending index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FindMode {
     Ascending,
     Descending,
 }
 
 
-pub fn find_next_element<T>(collection: &Vec<T>, starting_index: usize, element: &T, mode: FindMode) -> Option<usize>
+pub fn find_next_element<T>(collection: &[T], starting_index: usize, element: &T, mode: FindMode) -> Option<usize>
 where
     T: PartialEq<T>,
 {
-    match mode {
-        FindMode::Ascending => collection[starting_index + 1..].iter().position(|x| x == element).map(|i| i + starting_index + 1),
-        FindMode::Descending => (0..starting_index).rev().find(|&i| &collection[i] == element),
+    find_next_matching(collection, starting_index, mode, WrapMode::Bounded, |x| x == element).map(|m| m.index)
+}
+
+/// WrapMode defines if a search stops at the ends of the collection or continues around it circularly
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WrapMode {
+    Bounded,
+    /// The search wraps around and visits the starting element last, `starting_index` is taken modulo the length
+    Circular,
+}
+
+/// A matching element and how many steps it is from the starting index in the search direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElementMatch {
+    pub index: usize,
+    pub distance: usize,
+}
+
+/// The indices visited by a search from `starting_index` (excluded), with their distances
+fn search_order(len: usize, starting_index: usize, mode: FindMode, wrap: WrapMode) -> Box<dyn Iterator<Item = (usize, usize)>> {
+    match (wrap, mode) {
+        (WrapMode::Bounded, FindMode::Ascending) => {
+            Box::new((starting_index.saturating_add(1)..len).map(move |i| (i, i - starting_index)))
+        }
+        (WrapMode::Bounded, FindMode::Descending) => {
+            Box::new((0..starting_index.min(len)).rev().map(move |i| (i, starting_index - i)))
+        }
+        (WrapMode::Circular, _) if len == 0 => Box::new(std::iter::empty()),
+        (WrapMode::Circular, FindMode::Ascending) => {
+            let start = starting_index % len;
+            Box::new((1..=len).map(move |d| ((start + d) % len, d)))
+        }
+        (WrapMode::Circular, FindMode::Descending) => {
+            let start = starting_index % len;
+            Box::new((1..=len).map(move |d| ((start + len - d % len) % len, d)))
+        }
     }
 }
 
+/// The first element after `starting_index` in the search direction satisfying `predicate`
+pub fn find_next_matching<T, P>(collection: &[T], starting_index: usize, mode: FindMode, wrap: WrapMode, mut predicate: P) -> Option<ElementMatch>
+where
+    P: FnMut(&T) -> bool,
+{
+    search_order(collection.len(), starting_index, mode, wrap)
+        .find(|&(index, _)| predicate(&collection[index]))
+        .map(|(index, distance)| ElementMatch { index, distance })
+}
 
+/// Every element satisfying `predicate` in the order a search from `starting_index` reaches them
+pub fn find_all_matching<T, P>(collection: &[T], starting_index: usize, mode: FindMode, wrap: WrapMode, mut predicate: P) -> Vec<ElementMatch>
+where
+    P: FnMut(&T) -> bool,
+{
+    search_order(collection.len(), starting_index, mode, wrap)
+        .filter(|&(index, _)| predicate(&collection[index]))
+        .map(|(index, distance)| ElementMatch { index, distance })
+        .collect()
+}
 
 #[cfg(test)]
 mod tests {
@@ -67,6 +138,14 @@ mod tests {
         assert_eq!(*result.unwrap(), 2);
     }
 
+    #[test]
+    fn find_middle_element_empty() {
+        let input: [u32; 0] = [];
+        for mode in [FindMiddleElementMode::Error, FindMiddleElementMode::Left, FindMiddleElementMode::Right] {
+            assert_eq!(find_middle_element(&input, mode), Err("Collection is empty"));
+        }
+    }
+
     #[test]
     fn find_middle_element_even_count_right_mode() {
         let input = vec![1, 2, 3, 4];
@@ -115,4 +194,61 @@ mod tests {
         let result = find_next_element(&input, 4, &1, FindMode::Descending);
         assert_eq!(result, Some(0));
     }
+
+    #[test]
+    fn find_next_element_start_past_end() {
+        let input = vec![1, 2, 3];
+        assert_eq!(find_next_element(&input, 2, &3, FindMode::Ascending), None);
+        assert_eq!(find_next_element(&input, 5, &3, FindMode::Ascending), None);
+        assert_eq!(find_next_element(&input, 5, &3, FindMode::Descending), Some(2));
+    }
+
+    #[test]
+    fn find_next_matching_predicate() {
+        let input = vec![1, 2, 3, 4, 2, 5, 6];
+        let result = find_next_matching(&input, 1, FindMode::Ascending, WrapMode::Bounded, |&x| x > 3);
+        assert_eq!(result, Some(ElementMatch { index: 3, distance: 2 }));
+        let result = find_next_matching(&input, 5, FindMode::Descending, WrapMode::Bounded, |&x| x % 2 == 0);
+        assert_eq!(result, Some(ElementMatch { index: 4, distance: 1 }));
+        assert_eq!(find_next_matching(&input, 5, FindMode::Ascending, WrapMode::Bounded, |&x| x < 3), None);
+    }
+
+    #[test]
+    fn find_next_matching_circular() {
+        let input = vec![1, 2, 3, 4, 2, 5, 6];
+        let result = find_next_matching(&input, 5, FindMode::Ascending, WrapMode::Circular, |&x| x < 3);
+        assert_eq!(result, Some(ElementMatch { index: 0, distance: 2 }));
+        let result = find_next_matching(&input, 1, FindMode::Descending, WrapMode::Circular, |&x| x == 6);
+        assert_eq!(result, Some(ElementMatch { index: 6, distance: 2 }));
+        // Only the starting element matches, it is reached after a full lap
+        let result = find_next_matching(&input, 3, FindMode::Ascending, WrapMode::Circular, |&x| x == 4);
+        assert_eq!(result, Some(ElementMatch { index: 3, distance: 7 }));
+        assert_eq!(find_next_matching(&Vec::<i32>::new(), 0, FindMode::Ascending, WrapMode::Circular, |_| true), None);
+    }
+
+    #[test]
+    fn find_all_matching_in_search_order() {
+        let input = vec![1, 2, 3, 4, 2, 5, 6];
+        let indices = |matches: Vec<ElementMatch>| matches.iter().map(|m| m.index).collect::<Vec<_>>();
+
+        let bounded = find_all_matching(&input, 2, FindMode::Ascending, WrapMode::Bounded, |&x| x % 2 == 0);
+        assert_eq!(indices(bounded), vec![3, 4, 6]);
+        let circular = find_all_matching(&input, 2, FindMode::Ascending, WrapMode::Circular, |&x| x % 2 == 0);
+        assert_eq!(indices(circular.clone()), vec![3, 4, 6, 1]);
+        assert_eq!(circular.last().unwrap().distance, 6);
+        let descending = find_all_matching(&input, 2, FindMode::Descending, WrapMode::Circular, |&x| x == 2);
+        assert_eq!(indices(descending), vec![1, 4]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn modes_serde_round_trip() {
+        let json = serde_json::to_string(&FindMiddleElementMode::Left).unwrap();
+        assert_eq!(json, r#""Left""#);
+        assert_eq!(serde_json::from_str::<FindMiddleElementMode>(&json).unwrap(), FindMiddleElementMode::Left);
+
+        let json = serde_json::to_string(&FindMode::Descending).unwrap();
+        assert_eq!(json, r#""Descending""#);
+        assert_eq!(serde_json::from_str::<FindMode>(&json).unwrap(), FindMode::Descending);
+    }
 }
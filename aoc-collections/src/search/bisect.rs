@@ -0,0 +1,226 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::numeric::Integer;
+
+/// The inclusive `[low, high]` bounds of a range, None if it is empty
+fn inclusive_bounds<T: Integer>(range: &impl RangeBounds<T>) -> Option<(T, T)> {
+    let low = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add_delta(1)?,
+        Bound::Unbounded => T::MIN,
+    };
+    let high = match range.end_bound() {
+        Bound::Included(&end) => end,
+        Bound::Excluded(&end) => end.checked_add_delta(-1)?,
+        Bound::Unbounded => T::MAX,
+    };
+    (low <= high).then_some((low, high))
+}
+
+/// Binary search for the first value in inclusive `[low, high]` satisfying `predicate`, which must
+/// be false up to some point and true from then on
+fn first_true_between<T, P>(mut low: T, mut high: T, predicate: &mut P) -> Option<T>
+where
+    T: Integer,
+    P: FnMut(T) -> bool,
+{
+    if !predicate(high) {
+        return None;
+    }
+    // Invariant: the predicate holds at `high`, the answer is in `[low, high]`
+    while low < high {
+        let mid = low.floor_midpoint(high);
+        if predicate(mid) {
+            high = mid;
+        } else {
+            low = mid + T::ONE;
+        }
+    }
+    Some(low)
+}
+
+/// The first value in `range` for which a monotone (false then true) `predicate` is true, None if
+/// it is false for the whole range
+///
+/// Works on any range form, e.g. `0..n`, `1..=n`, `..` for the whole type.
+pub fn first_true<T, R, P>(range: R, mut predicate: P) -> Option<T>
+where
+    T: Integer,
+    R: RangeBounds<T>,
+    P: FnMut(T) -> bool,
+{
+    let (low, high) = inclusive_bounds(&range)?;
+    first_true_between(low, high, &mut predicate)
+}
+
+/// The last value in `range` for which a monotone (true then false) `predicate` is true, None if it
+/// is false for the whole range
+pub fn last_true<T, R, P>(range: R, mut predicate: P) -> Option<T>
+where
+    T: Integer,
+    R: RangeBounds<T>,
+    P: FnMut(T) -> bool,
+{
+    let (low, high) = inclusive_bounds(&range)?;
+    match first_true_between(low, high, &mut |x| !predicate(x)) {
+        Some(first_false) if first_false == low => None,
+        Some(first_false) => Some(first_false - T::ONE),
+        None => Some(high),
+    }
+}
+
+/// The first value from `start` upwards for which a monotone (false then true) `predicate` is
+/// true, for searches without a known upper bound
+///
+/// Probes `start`, `start + 1`, `start + 3`, `start + 7`, ... until the predicate holds and then
+/// binary searches the last gap. None if it never holds up to the type's maximum.
+pub fn first_true_from<T, P>(start: T, mut predicate: P) -> Option<T>
+where
+    T: Integer,
+    P: FnMut(T) -> bool,
+{
+    let mut low = start;
+    let mut step = T::ONE;
+    let mut probe = start;
+    loop {
+        if predicate(probe) {
+            return first_true_between(low, probe, &mut predicate);
+        }
+        if probe == T::MAX {
+            return None;
+        }
+        low = probe + T::ONE;
+        probe = probe.checked_add(step).unwrap_or(T::MAX);
+        step = step.checked_add(step).unwrap_or(T::MAX);
+    }
+}
+
+/// The index of the first element whose key is not less than `key` in a slice sorted by that key
+pub fn lower_bound_by_key<T, K, F>(collection: &[T], key: &K, mut f: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    collection.partition_point(|x| f(x) < *key)
+}
+
+/// The index of the first element whose key is greater than `key` in a slice sorted by that key
+pub fn upper_bound_by_key<T, K, F>(collection: &[T], key: &K, mut f: F) -> usize
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    collection.partition_point(|x| f(x) <= *key)
+}
+
+/// The index of the first element not less than `value` in a sorted slice
+pub fn lower_bound<T: Ord>(collection: &[T], value: &T) -> usize {
+    collection.partition_point(|x| x < value)
+}
+
+/// The index of the first element greater than `value` in a sorted slice
+pub fn upper_bound<T: Ord>(collection: &[T], value: &T) -> usize {
+    collection.partition_point(|x| x <= value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::traversal::bfs_until;
+    use std::collections::HashSet;
+
+    #[test]
+    fn first_true_ranges() {
+        assert_eq!(first_true(0u32..100, |x| x * x >= 50), Some(8));
+        assert_eq!(first_true(0u32..=7, |x| x * x >= 50), None);
+        assert_eq!(first_true(0u32..7, |x| x * x >= 49), None);
+        assert_eq!(first_true(0u32..=8, |_| true), Some(0));
+        assert_eq!(first_true(5u32..5, |_| true), None);
+
+        assert_eq!(first_true(-100i64..=100, |x| x >= -37), Some(-37));
+        assert_eq!(first_true(.., |x: i8| x >= 100), Some(100));
+        assert_eq!(first_true(.., |x: u64| x >= u64::MAX - 1), Some(u64::MAX - 1));
+        assert_eq!(first_true(.., |x: i64| x > -5), Some(-4));
+    }
+
+    #[test]
+    fn last_true_ranges() {
+        assert_eq!(last_true(0u32..100, |x| x * x <= 50), Some(7));
+        assert_eq!(last_true(0u32..5, |_| true), Some(4));
+        assert_eq!(last_true(3u32..5, |_| false), None);
+        assert_eq!(last_true(.., |x: i16| x < 0), Some(-1));
+    }
+
+    #[test]
+    fn exponential_probing() {
+        let mut calls = 0;
+        let result = first_true_from(0u64, |x| {
+            calls += 1;
+            x >= 1_000_000_007
+        });
+        assert_eq!(result, Some(1_000_000_007));
+        assert!(calls < 70);
+
+        assert_eq!(first_true_from(10i32, |x| x >= 3), Some(10));
+        assert_eq!(first_true_from(-50i32, |x| x >= -3), Some(-3));
+        assert_eq!(first_true_from(250u8, |_| false), None);
+        assert_eq!(first_true_from(250u8, |x| x == 255), Some(255));
+    }
+
+    #[test]
+    fn full_width_types() {
+        assert_eq!(first_true(.., |x: i128| x >= i128::MAX - 3), Some(i128::MAX - 3));
+        assert_eq!(first_true(.., |x: i128| x > i128::MIN), Some(i128::MIN + 1));
+        assert_eq!(first_true(-(1i128 << 100)..(1 << 100), |x| x >= 12_345), Some(12_345));
+        assert_eq!(last_true(.., |x: u128| x <= u128::MAX / 3), Some(u128::MAX / 3));
+        assert_eq!(first_true(i128::MIN..i128::MIN, |_| true), None);
+        assert_eq!(first_true_from(0u128, |x| x >= 1 << 90), Some(1 << 90));
+        assert_eq!(first_true_from(i128::MIN, |x| x >= 0), Some(0));
+    }
+
+    #[test]
+    fn bounds_by_key() {
+        let sorted = vec![1, 2, 2, 2, 5, 7];
+        assert_eq!(lower_bound(&sorted, &2), 1);
+        assert_eq!(upper_bound(&sorted, &2), 4);
+        assert_eq!(lower_bound(&sorted, &3), 4);
+        assert_eq!(upper_bound(&sorted, &9), 6);
+
+        let people = vec![("ann", 21), ("bob", 30), ("cat", 30), ("dan", 45)];
+        assert_eq!(lower_bound_by_key(&people, &30, |p| p.1), 1);
+        assert_eq!(upper_bound_by_key(&people, &30, |p| p.1), 3);
+        assert_eq!(lower_bound_by_key(&people, &"c", |p| p.0), 2);
+    }
+
+    const BYTES: &str = "5,4 4,2 4,5 3,0 2,1 6,3 2,4 1,5 0,6 3,3 2,6 5,1 1,2 5,5 2,5 6,5 1,4 0,4 6,4 1,1 6,1 1,0 0,5 1,6 2,0";
+
+    fn path_length(bytes: &[(i32, i32)]) -> Option<usize> {
+        let blocked = bytes.iter().copied().collect::<HashSet<_>>();
+        let traversal = bfs_until(
+            (0, 0),
+            |&(x, y): &(i32, i32)| {
+                [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                    .into_iter()
+                    .filter(|&(x, y)| (0..7).contains(&x) && (0..7).contains(&y) && !blocked.contains(&(x, y)))
+                    .collect::<Vec<_>>()
+            },
+            |&p| p == (6, 6),
+        );
+        traversal.distance(&(6, 6))
+    }
+
+    #[test]
+    fn first_blocking_byte() {
+        let bytes = BYTES
+            .split(' ')
+            .map(|b| {
+                let (x, y) = b.split_once(',').unwrap();
+                (x.parse().unwrap(), y.parse().unwrap())
+            })
+            .collect::<Vec<(i32, i32)>>();
+
+        assert_eq!(path_length(&bytes[..12]), Some(22));
+        let fallen = first_true(0..=bytes.len(), |n| path_length(&bytes[..n]).is_none()).unwrap();
+        assert_eq!(bytes[fallen - 1], (6, 1));
+    }
+}
@@ -0,0 +1,181 @@
+use crate::direction::Direction;
+use ndarray::{ArrayBase, Data, Ix2};
+
+/// Positions along a straight line from (but not including) a start position until the board edge
+pub struct Ray {
+    pos: [usize; 2],
+    offset: [isize; 2],
+    shape: [usize; 2],
+}
+
+impl Iterator for Ray {
+    type Item = [usize; 2];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row = self.pos[0].checked_add_signed(self.offset[0])?;
+        let col = self.pos[1].checked_add_signed(self.offset[1])?;
+        if row >= self.shape[0] || col >= self.shape[1] {
+            return None;
+        }
+        self.pos = [row, col];
+        Some(self.pos)
+    }
+}
+
+/// Result of a ray search, `distance` is the number of steps from the start position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RayHit {
+    pub pos: [usize; 2],
+    pub distance: usize,
+}
+
+pub fn cast_ray<S, T>(board: &ArrayBase<S, Ix2>, start: [usize; 2], direction: Direction) -> Ray
+where
+    S: Data<Elem = T>,
+{
+    Ray {
+        pos: start,
+        offset: direction.grid_offset(),
+        shape: [board.nrows(), board.ncols()],
+    }
+}
+
+/// The positions and values of every cell along a ray until the edge of the board
+pub fn ray_cells<'a, S, T>(
+    board: &'a ArrayBase<S, Ix2>,
+    start: [usize; 2],
+    direction: Direction,
+) -> impl Iterator<Item = ([usize; 2], &'a T)>
+where
+    S: Data<Elem = T>,
+    T: 'a,
+{
+    cast_ray(board, start, direction).map(move |p| (p, &board[p]))
+}
+
+/// The first cell along a ray matching the predicate
+pub fn find_along_ray<S, T, P>(
+    board: &ArrayBase<S, Ix2>,
+    start: [usize; 2],
+    direction: Direction,
+    mut predicate: P,
+) -> Option<RayHit>
+where
+    S: Data<Elem = T>,
+    P: FnMut(&T) -> bool,
+{
+    ray_cells(board, start, direction)
+        .enumerate()
+        .find(|(_, (_, v))| predicate(v))
+        .map(|(i, (pos, _))| RayHit { pos, distance: i + 1 })
+}
+
+/// Number of cells seen along a ray, stopping at (and including) the first cell that blocks the view
+pub fn viewing_distance<S, T, P>(board: &ArrayBase<S, Ix2>, start: [usize; 2], direction: Direction, blocks: P) -> usize
+where
+    S: Data<Elem = T>,
+    P: FnMut(&T) -> bool,
+{
+    match find_along_ray(board, start, direction, blocks) {
+        Some(hit) => hit.distance,
+        None => cast_ray(board, start, direction).count(),
+    }
+}
+
+/// True if nothing blocks the line of sight from the start position to the board edge
+pub fn is_visible_from_edge<S, T, P>(board: &ArrayBase<S, Ix2>, start: [usize; 2], direction: Direction, blocks: P) -> bool
+where
+    S: Data<Elem = T>,
+    P: FnMut(&T) -> bool,
+{
+    find_along_ray(board, start, direction, blocks).is_none()
+}
+
+/// Number of the given directions from which the start position can be seen from the board edge
+pub fn count_visible_directions<S, T, P>(
+    board: &ArrayBase<S, Ix2>,
+    start: [usize; 2],
+    directions: &[Direction],
+    mut blocks: P,
+) -> usize
+where
+    S: Data<Elem = T>,
+    P: FnMut(&T) -> bool,
+{
+    directions
+        .iter()
+        .filter(|&&d| is_visible_from_edge(board, start, d, &mut blocks))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_generators::processors::board_generator::generate_2d_board_char as board;
+
+    const TREES: &str = "30373\n25512\n65332\n33549\n35390";
+
+    #[test]
+    fn cast_ray_to_edge() {
+        let b = board(TREES);
+        assert_eq!(cast_ray(&b, [2, 2], Direction::North).collect::<Vec<_>>(), vec![[1, 2], [0, 2]]);
+        assert_eq!(cast_ray(&b, [2, 2], Direction::SouthEast).collect::<Vec<_>>(), vec![[3, 3], [4, 4]]);
+        assert_eq!(cast_ray(&b, [0, 0], Direction::West).count(), 0);
+        assert_eq!(cast_ray(&b.view(), [4, 0], Direction::NorthEast).count(), 4);
+    }
+
+    #[test]
+    fn ray_cells_values() {
+        let b = board(TREES);
+        let values = ray_cells(&b, [1, 0], Direction::East).map(|(_, v)| *v).collect::<String>();
+        assert_eq!(values, "5512");
+    }
+
+    #[test]
+    fn find_along_ray_hits() {
+        let b = board(TREES);
+        let hit = find_along_ray(&b, [3, 0], Direction::East, |c| *c == '9');
+        assert_eq!(hit, Some(RayHit { pos: [3, 4], distance: 4 }));
+        assert_eq!(find_along_ray(&b, [3, 0], Direction::West, |c| *c == '9'), None);
+    }
+
+    #[test]
+    fn tree_visibility() {
+        let b = board(TREES);
+        let visible = b
+            .indexed_iter()
+            .filter(|&((r, c), h)| count_visible_directions(&b, [r, c], &Direction::ORTHOGONAL, |t| t >= h) > 0)
+            .count();
+        assert_eq!(visible, 21);
+    }
+
+    #[test]
+    fn tree_scenic_score() {
+        let b = board(TREES);
+        let score = |pos: [usize; 2]| {
+            let height = b[pos];
+            Direction::ORTHOGONAL
+                .iter()
+                .map(|&d| viewing_distance(&b, pos, d, |t| *t >= height))
+                .product::<usize>()
+        };
+        assert_eq!(score([1, 2]), 4);
+        assert_eq!(score([3, 2]), 8);
+    }
+
+    #[test]
+    fn xmas_word_search() {
+        let b = board("MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\nXXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX");
+        let count = b
+            .indexed_iter()
+            .filter(|(_, c)| **c == 'X')
+            .map(|((r, c), _)| {
+                Direction::ALL
+                    .iter()
+                    .filter(|&&d| ray_cells(&b, [r, c], d).map(|(_, v)| *v).take(3).eq("MAS".chars()))
+                    .count()
+            })
+            .sum::<usize>();
+        assert_eq!(count, 18);
+    }
+}
@@ -0,0 +1,247 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use crate::counter::Counter;
+use crate::numeric::Number;
+use crate::search::{middle_index, FindMiddleElementMode};
+
+/// Moves the `k`-th smallest element (0 based) into position `k` using quickselect and returns it,
+/// smaller elements end up before it and larger ones after it
+pub fn select_kth<T: Ord>(collection: &mut [T], k: usize) -> Option<&T> {
+    if k >= collection.len() {
+        return None;
+    }
+    let (_, kth, _) = collection.select_nth_unstable(k);
+    Some(kth)
+}
+
+/// The `k`-th smallest element (0 based) without reordering the collection
+pub fn kth_smallest<T: Ord + Clone>(collection: &[T], k: usize) -> Option<T> {
+    let mut copy = collection.to_vec();
+    select_kth(&mut copy, k).cloned()
+}
+
+/// The `k`-th largest element (0 based) without reordering the collection
+pub fn kth_largest<T: Ord + Clone>(collection: &[T], k: usize) -> Option<T> {
+    k.checked_add(1)
+        .and_then(|n| collection.len().checked_sub(n))
+        .and_then(|i| kth_smallest(collection, i))
+}
+
+/// The median by value, `mode` picks the lower or upper median for an even number of elements
+pub fn median<T: Ord + Clone>(collection: &[T], mode: FindMiddleElementMode) -> Result<T, &'static str> {
+    let index = middle_index(collection.len(), mode)?;
+    Ok(kth_smallest(collection, index).unwrap())
+}
+
+/// The `percent` percentile (0 to 100) by value, the element at sorted position
+/// `percent * (len - 1) / 100`
+///
+/// When that position falls between two elements `mode` picks the lower or upper one.
+pub fn percentile<T: Ord + Clone>(collection: &[T], percent: usize, mode: FindMiddleElementMode) -> Result<T, &'static str> {
+    if collection.is_empty() {
+        return Err("Collection is empty");
+    }
+    if percent > 100 {
+        return Err("Percentile must be between 0 and 100");
+    }
+
+    let scaled = percent * (collection.len() - 1);
+    let (whole, remainder) = (scaled / 100, scaled % 100);
+    let index = if remainder == 0 {
+        whole
+    } else {
+        match mode {
+            FindMiddleElementMode::Error => return Err("Percentile falls between two elements and error mode used"),
+            FindMiddleElementMode::Left => whole,
+            FindMiddleElementMode::Right => whole + 1,
+        }
+    };
+    Ok(kth_smallest(collection, index).unwrap())
+}
+
+/// Running median of a stream of values, kept in a max heap of the lower half and a min heap of
+/// the upper half
+#[derive(Debug, Clone)]
+pub struct StreamingMedian<T: Ord> {
+    lower: BinaryHeap<T>,
+    upper: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> StreamingMedian<T> {
+    pub fn new() -> Self {
+        Self {
+            lower: BinaryHeap::new(),
+            upper: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        if self.lower.peek().is_none_or(|top| value <= *top) {
+            self.lower.push(value);
+        } else {
+            self.upper.push(Reverse(value));
+        }
+
+        // Keep the lower half the same size as the upper half or one larger
+        if self.lower.len() > self.upper.len() + 1 {
+            self.upper.push(Reverse(self.lower.pop().unwrap()));
+        } else if self.upper.len() > self.lower.len() {
+            self.lower.push(self.upper.pop().unwrap().0);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+
+    /// The median of the values pushed so far, `mode` as for `median`
+    pub fn median(&self, mode: FindMiddleElementMode) -> Result<&T, &'static str> {
+        // The lower half holds the first `lower.len()` values in sorted order
+        if middle_index(self.len(), mode)? < self.lower.len() {
+            Ok(self.lower.peek().unwrap())
+        } else {
+            Ok(&self.upper.peek().unwrap().0)
+        }
+    }
+}
+
+impl<T: Ord> Default for StreamingMedian<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Extend<T> for StreamingMedian<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for StreamingMedian<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut median = Self::new();
+        median.extend(iter);
+        median
+    }
+}
+
+/// The most frequent value, ties are broken by the smallest value
+pub fn mode<I>(values: I) -> Option<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Eq + Hash + Ord,
+{
+    let counter: Counter<I::Item> = values.into_iter().collect();
+    counter.into_iter().max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0))).map(|(value, _)| value)
+}
+
+/// The arithmetic mean, None for no values
+pub fn mean<I>(values: I) -> Option<f64>
+where
+    I: IntoIterator,
+    I::Item: Number,
+{
+    let (sum, count) = values.into_iter().fold((0.0, 0usize), |(sum, count), v| (sum + v.to_f64(), count + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kth_selection() {
+        let input = vec![7, 2, 9, 4, 1, 8, 3];
+        assert_eq!(kth_smallest(&input, 0), Some(1));
+        assert_eq!(kth_smallest(&input, 3), Some(4));
+        assert_eq!(kth_smallest(&input, 6), Some(9));
+        assert_eq!(kth_smallest(&input, 7), None);
+        assert_eq!(kth_largest(&input, 0), Some(9));
+        assert_eq!(kth_largest(&input, 2), Some(7));
+        assert_eq!(kth_largest(&input, 7), None);
+
+        let mut in_place = input.clone();
+        assert_eq!(select_kth(&mut in_place, 2), Some(&3));
+        assert!(in_place[..2].iter().all(|&x| x < 3));
+        assert!(in_place[3..].iter().all(|&x| x > 3));
+    }
+
+    #[test]
+    fn median_modes() {
+        assert_eq!(median(&[5, 1, 3], FindMiddleElementMode::Error), Ok(3));
+        assert_eq!(median(&[4, 1, 3, 2], FindMiddleElementMode::Left), Ok(2));
+        assert_eq!(median(&[4, 1, 3, 2], FindMiddleElementMode::Right), Ok(3));
+        assert_eq!(
+            median(&[4, 1, 3, 2], FindMiddleElementMode::Error),
+            Err("Collection has even number of elements and error mode used")
+        );
+        assert_eq!(median::<i32>(&[], FindMiddleElementMode::Left), Err("Collection is empty"));
+    }
+
+    #[test]
+    fn median_crab_alignment() {
+        // The cheapest position to align to with linear fuel cost is the median
+        let crabs = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        let target = median(&crabs, FindMiddleElementMode::Left).unwrap();
+        assert_eq!(target, 2);
+        assert_eq!(crabs.iter().map(|c: &i32| (c - target).abs()).sum::<i32>(), 37);
+    }
+
+    #[test]
+    fn percentiles() {
+        let input = (1..=11).rev().collect::<Vec<_>>();
+        assert_eq!(percentile(&input, 0, FindMiddleElementMode::Error), Ok(1));
+        assert_eq!(percentile(&input, 50, FindMiddleElementMode::Error), Ok(6));
+        assert_eq!(percentile(&input, 100, FindMiddleElementMode::Error), Ok(11));
+        assert_eq!(percentile(&input, 95, FindMiddleElementMode::Left), Ok(10));
+        assert_eq!(percentile(&input, 95, FindMiddleElementMode::Right), Ok(11));
+        assert!(percentile(&input, 95, FindMiddleElementMode::Error).is_err());
+        assert!(percentile(&input, 101, FindMiddleElementMode::Left).is_err());
+    }
+
+    #[test]
+    fn percentiles_on_exact_positions() {
+        // Positions that are whole numbers but not exact in floating point
+        let input = (1..=101).collect::<Vec<_>>();
+        assert_eq!(percentile(&input, 29, FindMiddleElementMode::Error), Ok(30));
+        assert_eq!(percentile(&input, 29, FindMiddleElementMode::Left), Ok(30));
+        assert_eq!(percentile(&input, 7, FindMiddleElementMode::Right), Ok(8));
+        for percent in 0..=100 {
+            assert_eq!(percentile(&input, percent, FindMiddleElementMode::Error), Ok(percent + 1));
+        }
+    }
+
+    #[test]
+    fn streaming_median_matches_batch() {
+        let input = vec![26, 3, 98, 14, 55, 3, 77, 41, 8, 62];
+        let mut stream = StreamingMedian::new();
+        assert!(stream.median(FindMiddleElementMode::Left).is_err());
+
+        for (i, &value) in input.iter().enumerate() {
+            stream.push(value);
+            for mode in [FindMiddleElementMode::Left, FindMiddleElementMode::Right] {
+                assert_eq!(stream.median(mode).copied(), median(&input[..=i], mode));
+            }
+        }
+        assert_eq!(stream.len(), 10);
+    }
+
+    #[test]
+    fn mode_and_mean() {
+        assert_eq!(mode(vec![3, 1, 3, 2, 1]), Some(1));
+        assert_eq!(mode("hello world".chars()), Some('l'));
+        assert_eq!(mode(Vec::<u8>::new()), None);
+
+        assert_eq!(mean(vec![1i64, 2, 3, 4]), Some(2.5));
+        assert_eq!(mean([0.5f32, 1.5].iter().copied()), Some(1.0));
+        assert_eq!(mean(Vec::<u32>::new()), None);
+    }
+}
@@ -0,0 +1,381 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Defines how many predecessors are recorded for each state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathMode {
+    /// Only the first predecessor found on a shortest path, the search stops at the first goal
+    Single,
+    /// Every predecessor on an equal cost path, the search continues until all goal states with the
+    /// optimal cost have been found. Step costs must be positive, zero cost cycles would make the
+    /// predecessors cyclic
+    AllShortest,
+}
+
+/// Costs and predecessors found by `dijkstra` or `astar`
+pub struct ShortestPaths<S, C> {
+    /// Lowest known cost from the start to every discovered state
+    pub costs: HashMap<S, C>,
+    /// Predecessors of each state on its lowest cost path(s), the start has none
+    pub predecessors: HashMap<S, Vec<S>>,
+    /// Goal states reached at the optimal cost, at most one in `PathMode::Single`
+    pub goals: Vec<S>,
+}
+
+impl<S, C> ShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy,
+{
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// The cost of the cheapest goal, None if no goal was reached
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goals.first().and_then(|g| self.cost(g))
+    }
+
+    /// One lowest cost path from the start to `state` inclusive
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.costs.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        let mut current = state;
+        while let Some(previous) = self.predecessors.get(current).and_then(|p| p.first()) {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Number of distinct lowest cost paths from the start to `state` (needs `PathMode::AllShortest`
+    /// to count more than one)
+    pub fn count_paths(&self, state: &S) -> u64 {
+        if !self.costs.contains_key(state) {
+            return 0;
+        }
+
+        // Post-order walk with an explicit stack, a state is counted once all its predecessors are
+        let mut counts: HashMap<S, u64> = HashMap::new();
+        let mut stack = vec![(state.clone(), false)];
+        while let Some((current, expanded)) = stack.pop() {
+            if counts.contains_key(&current) {
+                continue;
+            }
+            let previous = self.predecessors.get(&current).map_or(&[][..], Vec::as_slice);
+            if expanded {
+                let count = if previous.is_empty() { 1 } else { previous.iter().map(|p| counts[p]).sum() };
+                counts.insert(current, count);
+            } else {
+                stack.push((current.clone(), true));
+                stack.extend(previous.iter().filter(|p| !counts.contains_key(*p)).map(|p| (p.clone(), false)));
+            }
+        }
+        counts[state]
+    }
+
+    /// Every state on any lowest cost path to any of the targets
+    pub fn states_on_paths(&self, targets: &[S]) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack = targets.iter().filter(|t| self.costs.contains_key(t)).cloned().collect::<Vec<_>>();
+        while let Some(state) = stack.pop() {
+            if !seen.insert(state.clone()) {
+                continue;
+            }
+            if let Some(previous) = self.predecessors.get(&state) {
+                stack.extend(previous.iter().cloned());
+            }
+        }
+        seen
+    }
+
+    /// Enumerates every lowest cost path to `state`, this grows with `count_paths` so is only
+    /// suitable when there are few of them
+    pub fn all_paths(&self, state: &S) -> Vec<Vec<S>> {
+        if !self.costs.contains_key(state) {
+            return Vec::new();
+        }
+
+        // Partial paths are built backwards from `state` until they reach the start
+        let mut paths = Vec::new();
+        let mut stack = vec![vec![state.clone()]];
+        while let Some(mut path) = stack.pop() {
+            match self.predecessors.get(path.last().unwrap()) {
+                Some(previous) if !previous.is_empty() => {
+                    // Copy the path for every branch but the first, which extends it in place
+                    for p in previous[1..].iter().rev() {
+                        let mut branch = path.clone();
+                        branch.push(p.clone());
+                        stack.push(branch);
+                    }
+                    path.push(previous[0].clone());
+                    stack.push(path);
+                }
+                _ => {
+                    path.reverse();
+                    paths.push(path);
+                }
+            }
+        }
+        paths
+    }
+}
+
+struct HeapEntry<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for HeapEntry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for HeapEntry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for HeapEntry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for HeapEntry<S, C> {
+    // Reversed so the BinaryHeap pops the lowest estimate first, preferring the deeper entry on ties
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate).then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+/// Dijkstra's algorithm from `start`
+///
+/// `successors` returns the neighbouring states with the cost of moving to them, costs must not be
+/// negative. `C::default()` is used as the zero cost. Pass `|_| false` as `is_goal` to find the
+/// cost of every reachable state.
+pub fn dijkstra<S, C, F, I, G>(start: S, successors: F, is_goal: G, mode: PathMode) -> ShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(start, successors, |_| C::default(), is_goal, mode)
+}
+
+/// A* search from `start`
+///
+/// As `dijkstra` with a `heuristic` estimating the remaining cost to a goal. The heuristic must
+/// never overestimate and must be consistent for the costs (and all shortest paths) to be exact.
+pub fn astar<S, C, F, I, H, G>(
+    start: S,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+    mode: PathMode,
+) -> ShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut result = ShortestPaths {
+        costs: HashMap::from([(start.clone(), C::default())]),
+        predecessors: HashMap::new(),
+        goals: Vec::new(),
+    };
+
+    let mut settled = HashSet::new();
+    let mut goal_cost: Option<C> = None;
+    let mut heap = BinaryHeap::from([HeapEntry {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+
+    while let Some(HeapEntry { estimate, cost, state }) = heap.pop() {
+        if goal_cost.is_some_and(|g| estimate > g) {
+            break;
+        }
+        if result.costs.get(&state).is_some_and(|&c| cost > c) || !settled.insert(state.clone()) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            goal_cost.get_or_insert(cost);
+            result.goals.push(state.clone());
+            if mode == PathMode::Single {
+                break;
+            }
+            continue;
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            match result.costs.get(&next) {
+                Some(&known) if next_cost > known => continue,
+                Some(&known) if next_cost == known => {
+                    if mode == PathMode::AllShortest {
+                        let previous = result.predecessors.entry(next).or_default();
+                        if !previous.contains(&state) {
+                            previous.push(state.clone());
+                        }
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+
+            result.costs.insert(next.clone(), next_cost);
+            result.predecessors.insert(next.clone(), vec![state.clone()]);
+            heap.push(HeapEntry {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::Direction;
+
+    const REINDEER_MAZE: [&str; 15] = [
+        "###############",
+        "#.......#....E#",
+        "#.#.###.#.###.#",
+        "#.....#.#...#.#",
+        "#.###.#####.#.#",
+        "#.#.#.......#.#",
+        "#.#.#####.###.#",
+        "#...........#.#",
+        "###.#.#####.#.#",
+        "#...#.....#.#.#",
+        "#.#.#.###.#.#.#",
+        "#.....#...#.#.#",
+        "#.###.#.#.#.#.#",
+        "#S..#.....#...#",
+        "###############",
+    ];
+
+    fn maze_cell(pos: [usize; 2]) -> u8 {
+        REINDEER_MAZE[pos[0]].as_bytes()[pos[1]]
+    }
+
+    fn find(c: u8) -> [usize; 2] {
+        for (r, row) in REINDEER_MAZE.iter().enumerate() {
+            if let Some(col) = row.bytes().position(|b| b == c) {
+                return [r, col];
+            }
+        }
+        panic!("Not found")
+    }
+
+    fn reindeer_moves(&(pos, dir): &([usize; 2], Direction)) -> Vec<(([usize; 2], Direction), u32)> {
+        let [dr, dc] = dir.grid_offset();
+        let ahead = [(pos[0] as isize + dr) as usize, (pos[1] as isize + dc) as usize];
+        let mut moves = vec![((pos, dir.turn_left()), 1000), ((pos, dir.turn_right()), 1000)];
+        if maze_cell(ahead) != b'#' {
+            moves.push(((ahead, dir), 1));
+        }
+        moves
+    }
+
+    #[test]
+    fn dijkstra_reindeer_maze() {
+        let end = find(b'E');
+        let result = dijkstra((find(b'S'), Direction::East), reindeer_moves, |&(p, _)| p == end, PathMode::Single);
+        assert_eq!(result.goal_cost(), Some(7036));
+        assert_eq!(result.goals.len(), 1);
+
+        let path = result.path_to(&result.goals[0]).unwrap();
+        assert_eq!(path[0], (find(b'S'), Direction::East));
+        assert_eq!(path.last().unwrap().0, end);
+    }
+
+    #[test]
+    fn dijkstra_reindeer_maze_tiles_on_best_paths() {
+        let end = find(b'E');
+        let result = dijkstra((find(b'S'), Direction::East), reindeer_moves, |&(p, _)| p == end, PathMode::AllShortest);
+        assert_eq!(result.goal_cost(), Some(7036));
+
+        let tiles = result
+            .states_on_paths(&result.goals)
+            .into_iter()
+            .map(|(p, _)| p)
+            .collect::<HashSet<_>>();
+        assert_eq!(tiles.len(), 45);
+    }
+
+    fn open_grid(&(r, c): &(i32, i32)) -> Vec<((i32, i32), u32)> {
+        [(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)]
+            .into_iter()
+            .filter(|&(r, c)| (0..3).contains(&r) && (0..3).contains(&c))
+            .map(|p| (p, 1))
+            .collect()
+    }
+
+    #[test]
+    fn count_and_enumerate_all_paths() {
+        let result = dijkstra((0, 0), open_grid, |_| false, PathMode::AllShortest);
+        assert_eq!(result.cost(&(2, 2)), Some(4));
+        assert_eq!(result.count_paths(&(2, 2)), 6);
+        assert_eq!(result.count_paths(&(0, 2)), 1);
+        assert_eq!(result.count_paths(&(5, 5)), 0);
+
+        let paths = result.all_paths(&(1, 1));
+        assert_eq!(paths.len(), 2);
+        assert!(paths.contains(&vec![(0, 0), (0, 1), (1, 1)]));
+        assert!(paths.contains(&vec![(0, 0), (1, 0), (1, 1)]));
+    }
+
+    #[test]
+    fn long_paths_do_not_recurse() {
+        let line = |&x: &u32| if x < 50_000 { vec![(x + 1, 1)] } else { vec![] };
+        let result = dijkstra(0, line, |_| false, PathMode::AllShortest);
+        assert_eq!(result.count_paths(&50_000), 1);
+        assert_eq!(result.all_paths(&50_000)[0].len(), 50_001);
+    }
+
+    #[test]
+    fn single_mode_records_one_predecessor() {
+        let result = dijkstra((0, 0), open_grid, |_| false, PathMode::Single);
+        assert_eq!(result.count_paths(&(2, 2)), 1);
+        assert_eq!(result.path_to(&(2, 2)).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn astar_with_manhattan_heuristic() {
+        let goal = (2, 2);
+        let heuristic = |&(r, c): &(i32, i32)| ((goal.0 - r).abs() + (goal.1 - c).abs()) as u32;
+        let result = astar((0, 0), open_grid, heuristic, |&p| p == goal, PathMode::Single);
+        assert_eq!(result.goal_cost(), Some(4));
+        assert_eq!(result.path_to(&goal).unwrap().len(), 5);
+
+        let result = astar((0, 0), open_grid, heuristic, |&p| p == goal, PathMode::AllShortest);
+        assert_eq!(result.count_paths(&goal), 6);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let result = dijkstra((0, 0), open_grid, |&p| p == (9, 9), PathMode::Single);
+        assert_eq!(result.goal_cost(), None);
+        assert_eq!(result.path_to(&(9, 9)), None);
+        assert_eq!(result.costs.len(), 9);
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Everything discovered by a breadth or depth first traversal
+///
+/// For BFS `distance` is the shortest number of steps from the start, for DFS it is the depth in
+/// the DFS tree.
+pub struct Traversal<S> {
+    /// States in the order they were visited, starting with the start state
+    pub order: Vec<S>,
+    pub distances: HashMap<S, usize>,
+    /// Parent of every visited state except the start
+    pub parents: HashMap<S, S>,
+    /// The state satisfying the goal predicate, if one was given and reached
+    pub goal: Option<S>,
+}
+
+impl<S> Traversal<S>
+where
+    S: Clone + Eq + Hash,
+{
+    pub fn contains(&self, state: &S) -> bool {
+        self.distances.contains_key(state)
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// All reachable (visited) states in visiting order
+    pub fn reachable(&self) -> impl Iterator<Item = &S> {
+        self.order.iter()
+    }
+
+    /// The states from the start to `state` inclusive, None if it was not reached
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.contains(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        let mut current = state;
+        while let Some(parent) = self.parents.get(current) {
+            path.push(parent.clone());
+            current = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path to the goal state, None if there was no goal or it was not reached
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|g| self.path_to(g))
+    }
+}
+
+/// Breadth first traversal of every state reachable from `start`
+pub fn bfs<S, F, I>(start: S, successors: F) -> Traversal<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    bfs_until(start, successors, |_| false)
+}
+
+/// Breadth first traversal that stops as soon as a state satisfying `is_goal` is visited
+pub fn bfs_until<S, F, I, G>(start: S, mut successors: F, mut is_goal: G) -> Traversal<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut traversal = Traversal {
+        order: Vec::new(),
+        distances: HashMap::from([(start.clone(), 0)]),
+        parents: HashMap::new(),
+        goal: None,
+    };
+
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        traversal.order.push(state.clone());
+        if is_goal(&state) {
+            traversal.goal = Some(state);
+            break;
+        }
+
+        let distance = traversal.distances[&state];
+        for next in successors(&state) {
+            if traversal.distances.contains_key(&next) {
+                continue;
+            }
+            traversal.distances.insert(next.clone(), distance + 1);
+            traversal.parents.insert(next.clone(), state.clone());
+            queue.push_back(next);
+        }
+    }
+
+    traversal
+}
+
+/// Depth first traversal of every state reachable from `start`, successors are explored in the
+/// order they are returned
+pub fn dfs<S, F, I>(start: S, successors: F) -> Traversal<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    dfs_until(start, successors, |_| false)
+}
+
+/// Depth first traversal that stops as soon as a state satisfying `is_goal` is visited
+pub fn dfs_until<S, F, I, G>(start: S, mut successors: F, mut is_goal: G) -> Traversal<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut traversal = Traversal {
+        order: Vec::new(),
+        distances: HashMap::new(),
+        parents: HashMap::new(),
+        goal: None,
+    };
+
+    let mut visited = HashSet::new();
+    let mut stack: Vec<(S, Option<S>, usize)> = vec![(start, None, 0)];
+    while let Some((state, parent, depth)) = stack.pop() {
+        if !visited.insert(state.clone()) {
+            continue;
+        }
+
+        traversal.order.push(state.clone());
+        traversal.distances.insert(state.clone(), depth);
+        if let Some(parent) = parent {
+            traversal.parents.insert(state.clone(), parent);
+        }
+        if is_goal(&state) {
+            traversal.goal = Some(state);
+            break;
+        }
+
+        let next_states = successors(&state)
+            .into_iter()
+            .filter(|n| !visited.contains(n))
+            .collect::<Vec<_>>();
+        // Reversed so the first successor is popped (explored) first
+        for next in next_states.into_iter().rev() {
+            stack.push((next, Some(state.clone()), depth + 1));
+        }
+    }
+
+    traversal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: [&str; 5] = ["S..#.", ".#.#.", ".#...", ".####", "....E"];
+
+    fn open_neighbours(pos: &(usize, usize)) -> Vec<(usize, usize)> {
+        let (r, c) = *pos;
+        let mut result = Vec::new();
+        if r > 0 {
+            result.push((r - 1, c));
+        }
+        if c > 0 {
+            result.push((r, c - 1));
+        }
+        result.push((r + 1, c));
+        result.push((r, c + 1));
+        result
+            .into_iter()
+            .filter(|&(r, c)| MAZE.get(r).and_then(|row| row.as_bytes().get(c)).is_some_and(|b| *b != b'#'))
+            .collect()
+    }
+
+    #[test]
+    fn bfs_shortest_distances_and_path() {
+        let traversal = bfs((0, 0), open_neighbours);
+
+        assert_eq!(traversal.distance(&(4, 4)), Some(8));
+        assert_eq!(traversal.distance(&(0, 4)), Some(8));
+        assert!(!traversal.contains(&(0, 3)));
+        assert_eq!(traversal.reachable().count(), 17);
+
+        let path = traversal.path_to(&(4, 4)).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!(path[0], (0, 0));
+        assert_eq!(path[8], (4, 4));
+        assert!(path.windows(2).all(|w| open_neighbours(&w[0]).contains(&w[1])));
+    }
+
+    #[test]
+    fn bfs_until_goal() {
+        let traversal = bfs_until((0, 0), open_neighbours, |&p| p == (2, 2));
+        assert_eq!(traversal.goal, Some((2, 2)));
+        assert_eq!(traversal.goal_path().unwrap(), vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]);
+        assert!(!traversal.contains(&(4, 4)));
+    }
+
+    #[test]
+    fn bfs_goal_not_reached() {
+        let traversal = bfs_until((0, 0), open_neighbours, |&p| p == (0, 3));
+        assert_eq!(traversal.goal, None);
+        assert_eq!(traversal.goal_path(), None);
+    }
+
+    #[test]
+    fn dfs_reaches_everything() {
+        let traversal = dfs((0, 0), open_neighbours);
+        assert_eq!(traversal.reachable().count(), 17);
+        assert_eq!(traversal.order[0], (0, 0));
+
+        let path = traversal.path_to(&(4, 4)).unwrap();
+        assert_eq!(path.len() - 1, traversal.distance(&(4, 4)).unwrap());
+        assert!(path.windows(2).all(|w| open_neighbours(&w[0]).contains(&w[1])));
+    }
+
+    #[test]
+    fn dfs_explores_first_successor_first() {
+        let traversal = dfs_until(1u32, |&n| if n < 20 { vec![n * 2, n * 3] } else { vec![] }, |&n| n > 20);
+        assert_eq!(traversal.order, vec![1, 2, 4, 8, 16, 32]);
+        assert_eq!(traversal.goal, Some(32));
+    }
+
+    #[test]
+    fn bfs_with_compound_state() {
+        // State is (position, keys held), a door at position 3 needs the key found at position 5
+        let traversal = bfs_until(
+            (4i32, false),
+            |&(pos, key): &(i32, bool)| {
+                [pos - 1, pos + 1]
+                    .into_iter()
+                    .filter(move |&p| (0..=6).contains(&p) && (p != 3 || key))
+                    .map(move |p| (p, key || p == 5))
+                    .collect::<Vec<_>>()
+            },
+            |&(pos, key)| pos == 0 && key,
+        );
+        // Has to fetch the key first: 4 -> 5 -> 4 -> 3 -> 2 -> 1 -> 0
+        assert_eq!(traversal.distance(&(0, true)), Some(6));
+    }
+}
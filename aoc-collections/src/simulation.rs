@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A cycle in a sequence of states: the state after `start + length` steps equals the state after
+/// `start` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps before the cycle is first entered
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step whose state is identical to the state after `n` steps
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// Extrapolates a value that grows by the same amount every time the cycle repeats (e.g. a
+    /// tower height), `values[i]` being the value after `i` steps
+    ///
+    /// `values` must cover at least `start + length + 1` steps.
+    pub fn extrapolate(&self, values: &[i64], n: usize) -> i64 {
+        if n < values.len() {
+            return values[n];
+        }
+
+        let repeats = ((n - self.start) / self.length) as i64;
+        let offset = (n - self.start) % self.length;
+        let per_cycle = values[self.start + self.length] - values[self.start];
+        values[self.start + offset] + repeats * per_cycle
+    }
+}
+
+/// A detected cycle with every state seen up to and including the first repeat
+pub struct CycleHistory<S> {
+    pub cycle: Cycle,
+    /// `history[i]` is the state after `i` steps, `start + length + 1` states in total
+    pub history: Vec<S>,
+}
+
+impl<S> CycleHistory<S> {
+    /// The state after `n` steps without simulating them
+    ///
+    /// After `detect_cycle_by_key` only the key is known to repeat, so the returned state is the
+    /// one from the equivalent earlier step: its key matches the state after `n` steps but any
+    /// other part (e.g. a growing height) does not, see `Cycle::extrapolate` for those.
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.history[self.cycle.equivalent_step(n)]
+    }
+
+    /// The values derived from each state in the history, e.g. for `Cycle::extrapolate`
+    pub fn values<V, F>(&self, derive: F) -> Vec<V>
+    where
+        F: FnMut(&S) -> V,
+    {
+        self.history.iter().map(derive).collect()
+    }
+}
+
+/// Runs `step` from `initial` until a state repeats, remembering every state in a hash map
+///
+/// Never returns if the states do not repeat.
+pub fn detect_cycle<S, F>(initial: S, step: F) -> CycleHistory<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    detect_cycle_by_key(initial, step, |s| s.clone())
+}
+
+/// As `detect_cycle` but states are compared by a key derived from them
+///
+/// Useful when the state carries values that keep growing (a height, a step counter) while the
+/// part that determines the future repeats.
+pub fn detect_cycle_by_key<S, K, F, KF>(initial: S, mut step: F, mut key: KF) -> CycleHistory<S>
+where
+    K: Eq + Hash,
+    F: FnMut(&S) -> S,
+    KF: FnMut(&S) -> K,
+{
+    let mut seen = HashMap::from([(key(&initial), 0)]);
+    let mut history = vec![initial];
+
+    loop {
+        let next = step(history.last().unwrap());
+        let next_key = key(&next);
+        history.push(next);
+
+        let steps = history.len() - 1;
+        if let Some(&start) = seen.get(&next_key) {
+            return CycleHistory {
+                cycle: Cycle {
+                    start,
+                    length: steps - start,
+                },
+                history,
+            };
+        }
+        seen.insert(next_key, steps);
+    }
+}
+
+/// Brent's cycle detection, only needs `PartialEq` and keeps a constant number of states
+///
+/// Never returns if the states do not repeat.
+pub fn detect_cycle_brent<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    // Find the cycle length by advancing the hare in increasing powers of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Then find the start with the hare `length` steps ahead of the tortoise
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The state after `n` steps, detecting a cycle with Brent's algorithm and skipping the repeats
+pub fn state_at_brent<S, F>(initial: S, mut step: F, n: usize) -> S
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let cycle = detect_cycle_brent(initial.clone(), &mut step);
+    let mut state = initial;
+    for _ in 0..cycle.equivalent_step(n) {
+        state = step(&state);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next(x: &u32) -> u32 {
+        (x * x + 1) % 255
+    }
+
+    fn brute_force(n: usize) -> u32 {
+        (0..n).fold(3, |x, _| next(&x))
+    }
+
+    #[test]
+    fn detect_cycle_hash() {
+        let result = detect_cycle(3, next);
+        let cycle = result.cycle;
+        assert_eq!(result.history.len(), cycle.start + cycle.length + 1);
+        assert_eq!(result.history[cycle.start], result.history[cycle.start + cycle.length]);
+
+        for n in [0, 1, 5, 100, 1000] {
+            assert_eq!(*result.state_at(n), brute_force(n));
+        }
+    }
+
+    #[test]
+    fn brent_matches_hash() {
+        let hashed = detect_cycle(3, next).cycle;
+        let brent = detect_cycle_brent(3, next);
+        assert_eq!(brent, hashed);
+
+        assert_eq!(state_at_brent(3, next, 77), brute_force(77));
+        assert_eq!(state_at_brent(3, next, 1_000_000_000), *detect_cycle(3, next).state_at(1_000_000_000));
+    }
+
+    #[test]
+    fn pure_cycle_starts_at_zero() {
+        let cycle = detect_cycle_brent(0u8, |x| (x + 1) % 7);
+        assert_eq!(cycle, Cycle { start: 0, length: 7 });
+        assert_eq!(cycle.equivalent_step(15), 1);
+    }
+
+    #[test]
+    fn extrapolate_growing_value() {
+        // (phase, height): the phase repeats every 5 steps after a 3 step run in, the height keeps growing
+        let step = |&(phase, height): &(u32, i64)| {
+            let next_phase = if phase < 3 { phase + 1 } else { 3 + (phase - 2) % 5 };
+            (next_phase, height + next_phase as i64)
+        };
+        let result = detect_cycle_by_key((0, 0), step, |&(phase, _)| phase);
+        assert_eq!(result.cycle, Cycle { start: 3, length: 5 });
+
+        let heights = result.values(|&(_, h)| h);
+        let brute = |n: usize| (0..n).fold((0, 0), |s, _| step(&s)).1;
+        for n in [2, 8, 9, 57, 1000] {
+            assert_eq!(result.cycle.extrapolate(&heights, n), brute(n));
+        }
+
+        // Only the keyed phase of the equivalent state matches, its height is from an earlier step
+        let brute_state = (0..57).fold((0, 0), |s, _| step(&s));
+        assert_eq!(result.state_at(57).0, brute_state.0);
+        assert_ne!(result.state_at(57).1, brute_state.1);
+    }
+}
@@ -33,6 +33,7 @@ where
 #[cfg(test)]
 mod tests {
     use std::fs;
+    #[cfg(feature = "regex")]
     use crate::processors::regex_line_processor::{RegexLineProcessor, RegexLineProcessorMode};
     use super::*;
 
@@ -54,6 +55,7 @@ mod tests {
         } else { panic!("Failed to read file"); }
     }
 
+    #[cfg(feature = "regex")]
     #[test]
     fn test_split_on_regex_striped() {
         let mut processor = RegexLineProcessor::new(r"(\d+)", RegexLineProcessorMode::Split(true));
@@ -85,6 +87,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "regex")]
     #[test]
     fn test_regex_matches() {
         let processor = RegexLineProcessor::new(r"(\d+)", RegexLineProcessorMode::Matches);
@@ -102,6 +105,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "regex")]
     #[test]
     fn test_regex_first_last() {
         let processor = RegexLineProcessor::new(r"(\d+)", RegexLineProcessorMode::FirstLast);
@@ -119,6 +123,17 @@ mod tests {
         }
     }
 
+    #[cfg(all(feature = "regex", feature = "serde"))]
+    #[test]
+    fn test_regex_mode_serde_round_trip() {
+        let json = serde_json::to_string(&RegexLineProcessorMode::Split(true)).unwrap();
+        assert_eq!(json, r#"{"Split":true}"#);
+        assert_eq!(serde_json::from_str::<RegexLineProcessorMode>(&json).unwrap(), RegexLineProcessorMode::Split(true));
+
+        let mode: RegexLineProcessorMode = serde_json::from_str(r#""FirstLast""#).unwrap();
+        assert_eq!(mode, RegexLineProcessorMode::FirstLast);
+    }
+
     struct RepeatingLineProcessor {}
 
     impl LineProcessor for RepeatingLineProcessor {
@@ -1,3 +1,6 @@
+#[cfg(feature = "regex")]
 pub mod regex_line_processor;
 pub mod line_processor_trait;
+pub mod range_processor;
+#[cfg(feature = "ndarray")]
 pub mod board_generator;
\ No newline at end of file
@@ -3,16 +3,51 @@ use ndarray::{Array, Array2};
 pub fn generate_2d_board_char(input: &str) -> Array2<char>{
     let input = input.trim();
     let row_count = input.lines().count();
-    let chars = input.lines().map(|l| l.chars()).flatten().collect::<Vec<_>>();
+    let chars = input.lines().flat_map(|l| l.chars()).collect::<Vec<_>>();
     let row_length = (chars.len()) / row_count;
 
     let a = Array::from_iter(chars);
 
-    let board = a.into_shape_with_order((row_count, row_length)).unwrap();
-    board
+    a.into_shape_with_order((row_count, row_length)).unwrap()
 
 }
 
+/// Renders a board back into the text form accepted by generate_2d_board_char (one line per row)
+pub fn board_to_string(board: &Array2<char>) -> String {
+    board.rows().into_iter().map(|r| r.iter().collect::<String>()).collect::<Vec<_>>().join("\n")
+}
+
+/// Serde support for `Array2<char>` boards, stored as a list of row strings so cached boards stay
+/// readable, use with `#[serde(with = "aoc_generators::processors::board_generator::board_serde")]`
+#[cfg(feature = "serde")]
+pub mod board_serde {
+    use ndarray::Array2;
+    use super::board_to_string;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(board: &Array2<char>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(board_to_string(board).lines())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Array2<char>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let rows = Vec::<String>::deserialize(deserializer)?;
+        let row_length = rows.first().map(|r| r.chars().count()).unwrap_or(0);
+        if rows.iter().any(|r| r.chars().count() != row_length) {
+            return Err(D::Error::custom("All board rows must be the same length"));
+        }
+
+        let chars = rows.iter().flat_map(|r| r.chars()).collect::<Vec<_>>();
+        Array2::from_shape_vec((rows.len(), row_length), chars).map_err(D::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -25,4 +60,33 @@ mod tests {
         let board = generate_2d_board_char(&input);
         println!("Board: {:#?}", board);
     }
+
+    #[test]
+    fn test_board_to_string_round_trip() {
+        let input = fs::read_to_string("resources/aoc24_4_test.txt").unwrap();
+
+        let board = generate_2d_board_char(&input);
+        assert_eq!(board_to_string(&board), input.trim());
+        assert_eq!(generate_2d_board_char(&board_to_string(&board)), board);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_board_serde_round_trip() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Snapshot {
+            #[serde(with = "board_serde")]
+            board: Array2<char>,
+        }
+
+        let snapshot = Snapshot { board: generate_2d_board_char("ab\ncd\nef") };
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(json, r#"{"board":["ab","cd","ef"]}"#);
+
+        let restored: Snapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.board, snapshot.board);
+
+        let ragged = serde_json::from_str::<Snapshot>(r#"{"board":["ab","c"]}"#);
+        assert!(ragged.is_err());
+    }
 }
\ No newline at end of file
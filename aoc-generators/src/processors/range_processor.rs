@@ -0,0 +1,119 @@
+use std::ops::Range;
+use crate::processors::line_processor_trait::LineProcessor;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RangeProcessorMode {
+    /// Inclusive `a-b` ranges separated by commas or whitespace, e.g. `11-22,95-115`
+    Dash,
+    /// Whitespace separated `start len` pairs, after an optional label ending in `:` such as `seeds:`
+    StartLength,
+}
+
+/// Parses the ranges on a line into half-open `Range<i64>`s
+pub struct RangeProcessor {
+    mode: RangeProcessorMode,
+}
+
+impl RangeProcessor {
+    pub fn new(mode: RangeProcessorMode) -> Self {
+        Self { mode }
+    }
+
+    fn parse_number(token: &str) -> Result<i64, String> {
+        token.parse().map_err(|_| format!("Invalid number: {}", token))
+    }
+
+    fn dash_ranges(line: &str) -> Result<Vec<Range<i64>>, String> {
+        line.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|token| !token.is_empty())
+            .map(|token| {
+                // Skip the first character so a negative start is not taken as the separator
+                let separator = token
+                    .char_indices()
+                    .skip(1)
+                    .find(|&(_, c)| c == '-')
+                    .map(|(i, _)| i)
+                    .ok_or_else(|| format!("Invalid range: {}", token))?;
+                let start = Self::parse_number(&token[..separator])?;
+                let end = Self::parse_number(&token[separator + 1..])?;
+                if end < start {
+                    return Err(format!("Invalid range: {}", token));
+                }
+                let end = end.checked_add(1).ok_or_else(|| format!("Range end too large: {}", token))?;
+                Ok(start..end)
+            })
+            .collect()
+    }
+
+    fn start_length_ranges(line: &str) -> Result<Vec<Range<i64>>, String> {
+        let values = line.split_once(':').map_or(line, |(_, values)| values);
+        let numbers = values
+            .split_whitespace()
+            .map(Self::parse_number)
+            .collect::<Result<Vec<_>, _>>()?;
+        if !numbers.len().is_multiple_of(2) {
+            return Err(format!("Odd number of values for start length pairs: {}", line));
+        }
+        numbers
+            .chunks(2)
+            .map(|pair| {
+                let (start, length) = (pair[0], pair[1]);
+                if length < 0 {
+                    return Err(format!("Negative range length: {} {}", start, length));
+                }
+                let end = start
+                    .checked_add(length)
+                    .ok_or_else(|| format!("Range end too large: {} {}", start, length))?;
+                Ok(start..end)
+            })
+            .collect()
+    }
+}
+
+impl LineProcessor for RangeProcessor {
+    type Item = Vec<Range<i64>>;
+    type ProcessorError = String;
+    fn process(&self, line: &str) -> Result<Self::Item, Self::ProcessorError> {
+        match self.mode {
+            RangeProcessorMode::Dash => Self::dash_ranges(line),
+            RangeProcessorMode::StartLength => Self::start_length_ranges(line),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dash_notation() {
+        let processor = RangeProcessor::new(RangeProcessorMode::Dash);
+        assert_eq!(processor.process("11-22,95-115"), Ok(vec![11..23, 95..116]));
+        assert_eq!(processor.process("3-5 8-8"), Ok(vec![3..6, 8..9]));
+        assert_eq!(processor.process("-5--2 7-7"), Ok(vec![-5..-1, 7..8]));
+        assert_eq!(processor.process("5-3"), Err("Invalid range: 5-3".to_string()));
+        assert_eq!(processor.process("12"), Err("Invalid range: 12".to_string()));
+        assert_eq!(processor.process("5-9223372036854775806 1-1"), Ok(vec![5..i64::MAX, 1..2]));
+        assert_eq!(
+            processor.process("5-9223372036854775807"),
+            Err("Range end too large: 5-9223372036854775807".to_string())
+        );
+    }
+
+    #[test]
+    fn start_length_notation() {
+        let processor = RangeProcessor::new(RangeProcessorMode::StartLength);
+        assert_eq!(processor.process("seeds: 79 14 55 13"), Ok(vec![79..93, 55..68]));
+        assert_eq!(processor.process("79 14 0 3"), Ok(vec![79..93, 0..3]));
+        assert!(processor.process("seeds: 79 14 55").is_err());
+        assert_eq!(processor.process("seeds: 79 x 14 55 13"), Err("Invalid number: x".to_string()));
+        assert_eq!(processor.process("seeds 79 14"), Err("Invalid number: seeds".to_string()));
+        assert_eq!(processor.process("79 -3"), Err("Negative range length: 79 -3".to_string()));
+        assert_eq!(processor.process("5 0 2 1"), Ok(vec![5..5, 2..3]));
+        assert_eq!(
+            processor.process("9223372036854775800 10"),
+            Err("Range end too large: 9223372036854775800 10".to_string())
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use regex::Regex;
 use crate::processors::line_processor_trait::LineProcessor;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RegexLineProcessorMode {
     Split(bool),
     Matches,
@@ -44,12 +46,12 @@ impl RegexLineProcessor {
     fn regex_first_last(&self, line: &str) -> Result<Vec<String>,String> {
         let regex_matches = self.regex_matches(line).unwrap();
 
-        if regex_matches.len() == 0 {
+        if regex_matches.is_empty() {
             Ok(Vec::new())
         } else if regex_matches.len() == 1 {
-            return Ok(vec![regex_matches[0].as_str().to_string(), regex_matches[0].as_str().to_string()])
+            Ok(vec![regex_matches[0].as_str().to_string(), regex_matches[0].as_str().to_string()])
         } else {
-            return Ok(vec![regex_matches[0].as_str().to_string(), regex_matches[regex_matches.len() - 1].as_str().to_string()])
+            Ok(vec![regex_matches[0].as_str().to_string(), regex_matches[regex_matches.len() - 1].as_str().to_string()])
         }
     }
 }
@@ -0,0 +1,4 @@
+pub mod linear;
+pub mod number_theory;
+pub mod rational;
+pub mod sequence;
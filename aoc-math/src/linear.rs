@@ -0,0 +1,212 @@
+use crate::rational::Rational;
+
+/// The outcome of solving a system of linear equations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinearSolution {
+    /// The equations contradict each other
+    None,
+    Unique(Vec<Rational>),
+    /// One solution (every free variable set to zero) and the variables that can take any value
+    Infinite {
+        particular: Vec<Rational>,
+        free_variables: Vec<usize>,
+    },
+}
+
+impl LinearSolution {
+    /// The unique solution if every value is an integer
+    pub fn integral(&self) -> Option<Vec<i128>> {
+        match self {
+            LinearSolution::Unique(values) => values.iter().map(Rational::to_integer).collect(),
+            _ => None,
+        }
+    }
+}
+
+/// Solves `coefficients * x = constants` exactly by Gauss-Jordan elimination
+///
+/// Each row of `coefficients` is one equation, every row must have the same length and there must
+/// be one constant per row.
+pub fn solve(coefficients: &[Vec<Rational>], constants: &[Rational]) -> LinearSolution {
+    assert_eq!(coefficients.len(), constants.len(), "One constant is needed per equation");
+    let variables = coefficients.first().map_or(0, |row| row.len());
+    assert!(
+        coefficients.iter().all(|row| row.len() == variables),
+        "Every equation needs the same number of coefficients"
+    );
+
+    let mut rows = coefficients
+        .iter()
+        .zip(constants)
+        .map(|(row, &constant)| {
+            let mut augmented = row.clone();
+            augmented.push(constant);
+            augmented
+        })
+        .collect::<Vec<_>>();
+
+    let mut pivot_columns = Vec::new();
+    for column in 0..variables {
+        let pivot_row = pivot_columns.len();
+        let Some(found) = (pivot_row..rows.len()).find(|&r| !rows[r][column].is_zero()) else {
+            continue;
+        };
+        rows.swap(pivot_row, found);
+
+        let pivot = rows[pivot_row][column];
+        for value in rows[pivot_row].iter_mut() {
+            *value /= pivot;
+        }
+        let pivot_values = rows[pivot_row].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if r == pivot_row || factor.is_zero() {
+                continue;
+            }
+            for (value, &pivot_value) in row.iter_mut().zip(&pivot_values).skip(column) {
+                *value -= factor * pivot_value;
+            }
+        }
+        pivot_columns.push(column);
+    }
+
+    // Any remaining row reads 0 = constant
+    if rows[pivot_columns.len()..].iter().any(|row| !row[variables].is_zero()) {
+        return LinearSolution::None;
+    }
+
+    let mut particular = vec![Rational::ZERO; variables];
+    for (row, &column) in pivot_columns.iter().enumerate() {
+        particular[column] = rows[row][variables];
+    }
+    if pivot_columns.len() == variables {
+        LinearSolution::Unique(particular)
+    } else {
+        let free_variables = (0..variables).filter(|c| !pivot_columns.contains(c)).collect();
+        LinearSolution::Infinite {
+            particular,
+            free_variables,
+        }
+    }
+}
+
+/// The solution of a square integer system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SquareSolution<const N: usize> {
+    None,
+    Infinite,
+    /// The unique solution, every value an integer
+    Integral([i128; N]),
+    /// The unique solution, at least one value has a fractional part
+    Fractional([Rational; N]),
+}
+
+/// Solves an `N` by `N` integer system `coefficients * x = constants` exactly
+pub fn solve_square<const N: usize>(coefficients: [[i64; N]; N], constants: [i64; N]) -> SquareSolution<N> {
+    let coefficients = coefficients
+        .iter()
+        .map(|row| row.iter().map(|&c| Rational::from(c)).collect())
+        .collect::<Vec<_>>();
+    let constants = constants.map(Rational::from);
+
+    match solve(&coefficients, &constants) {
+        LinearSolution::None => SquareSolution::None,
+        LinearSolution::Infinite { .. } => SquareSolution::Infinite,
+        LinearSolution::Unique(values) => {
+            let values: [Rational; N] = values.try_into().unwrap();
+            if values.iter().all(Rational::is_integer) {
+                SquareSolution::Integral(values.map(|v| v.numer()))
+            } else {
+                SquareSolution::Fractional(values)
+            }
+        }
+    }
+}
+
+/// Solves the pair of equations `a[i][0] * x + a[i][1] * y = b[i]`
+pub fn solve_2x2(a: [[i64; 2]; 2], b: [i64; 2]) -> SquareSolution<2> {
+    solve_square(a, b)
+}
+
+/// Solves the three equations `a[i][0] * x + a[i][1] * y + a[i][2] * z = b[i]`
+pub fn solve_3x3(a: [[i64; 3]; 3], b: [i64; 3]) -> SquareSolution<3> {
+    solve_square(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rationals(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|&v| Rational::from(v)).collect()
+    }
+
+    #[test]
+    fn unique_solution() {
+        let coefficients = vec![rationals(&[2, 1, -1]), rationals(&[-3, -1, 2]), rationals(&[-2, 1, 2])];
+        let solution = solve(&coefficients, &rationals(&[8, -11, -3]));
+        assert_eq!(solution, LinearSolution::Unique(rationals(&[2, 3, -1])));
+        assert_eq!(solution.integral(), Some(vec![2, 3, -1]));
+    }
+
+    #[test]
+    fn no_solution() {
+        let coefficients = vec![rationals(&[1, 1]), rationals(&[2, 2])];
+        assert_eq!(solve(&coefficients, &rationals(&[1, 3])), LinearSolution::None);
+    }
+
+    #[test]
+    fn infinite_solutions() {
+        let coefficients = vec![rationals(&[1, 2, 3]), rationals(&[2, 4, 6]), rationals(&[0, 0, 1])];
+        let solution = solve(&coefficients, &rationals(&[6, 12, 1]));
+        assert_eq!(
+            solution,
+            LinearSolution::Infinite {
+                particular: rationals(&[3, 0, 1]),
+                free_variables: vec![1],
+            }
+        );
+        assert_eq!(solution.integral(), None);
+    }
+
+    #[test]
+    fn overdetermined_consistent() {
+        let coefficients = vec![rationals(&[1, 0]), rationals(&[0, 1]), rationals(&[1, 1])];
+        assert_eq!(solve(&coefficients, &rationals(&[2, 5, 7])), LinearSolution::Unique(rationals(&[2, 5])));
+        assert_eq!(solve(&coefficients, &rationals(&[2, 5, 8])), LinearSolution::None);
+    }
+
+    #[test]
+    fn claw_machines() {
+        // [button A, button B] moves per axis and the prize position
+        let machines = [
+            ([[94, 22], [34, 67]], [8400, 5400]),
+            ([[26, 67], [66, 21]], [12748, 12176]),
+            ([[17, 84], [86, 37]], [7870, 6450]),
+            ([[69, 27], [23, 71]], [18641, 10279]),
+        ];
+        let tokens = machines
+            .iter()
+            .filter_map(|&(a, b)| match solve_2x2(a, b) {
+                SquareSolution::Integral([presses_a, presses_b]) => Some(3 * presses_a + presses_b),
+                _ => None,
+            })
+            .sum::<i128>();
+        assert_eq!(tokens, 480);
+        assert!(matches!(solve_2x2(machines[1].0, machines[1].1), SquareSolution::Fractional(_)));
+    }
+
+    #[test]
+    fn three_by_three() {
+        assert_eq!(
+            solve_3x3([[1, 1, 1], [0, 2, 5], [2, 5, -1]], [6, -4, 27]),
+            SquareSolution::Integral([5, 3, -2])
+        );
+        assert_eq!(
+            solve_3x3([[2, 0, 0], [0, 3, 0], [0, 0, 1]], [1, 1, 1]),
+            SquareSolution::Fractional([Rational::new(1, 2), Rational::new(1, 3), Rational::ONE])
+        );
+        assert_eq!(solve_3x3([[1, 1, 1], [1, 1, 1], [0, 0, 1]], [1, 1, 1]), SquareSolution::Infinite);
+        assert_eq!(solve_3x3([[1, 1, 1], [1, 1, 1], [0, 0, 1]], [1, 2, 1]), SquareSolution::None);
+    }
+}
@@ -0,0 +1,327 @@
+/// Greatest common divisor, always non-negative and `gcd(0, 0) == 0`
+///
+/// Panics if the result is 2^63, which only happens when one value is `i64::MIN` and the other is
+/// 0 or also `i64::MIN`, see `checked_gcd`.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    checked_gcd(a, b).expect("Greatest common divisor overflows i64")
+}
+
+/// `gcd`, None if the result does not fit in an `i64`
+pub fn checked_gcd(a: i64, b: i64) -> Option<i64> {
+    i64::try_from(gcd_i128(a as i128, b as i128)).ok()
+}
+
+/// `gcd` for the 128-bit intermediates used by `crt` and `Rational`
+pub(crate) fn gcd_i128(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a as i128
+}
+
+/// Least common multiple, always non-negative and 0 if either value is 0
+///
+/// Panics if the result does not fit in an `i64`, see `checked_lcm`.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    checked_lcm(a, b).expect("Least common multiple overflows i64")
+}
+
+/// `lcm`, None if the result does not fit in an `i64`
+pub fn checked_lcm(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / checked_gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+/// Greatest common divisor of every value, 0 for no values
+pub fn gcd_all<I: IntoIterator<Item = i64>>(values: I) -> i64 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// Least common multiple of every value, 1 for no values, None if it does not fit in an `i64`
+pub fn lcm_all<I: IntoIterator<Item = i64>>(values: I) -> Option<i64> {
+    values.into_iter().try_fold(1, checked_lcm)
+}
+
+/// Extended Euclid, returns `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+    (old_r as i64, old_x as i64, old_y as i64)
+}
+
+/// `(a * b) mod m` in `0..m` without overflowing
+///
+/// Panics if `m` is not positive.
+pub fn mod_mul(a: i64, b: i64, m: i64) -> i64 {
+    assert!(m > 0, "Modulus must be positive, got {}", m);
+    ((a as i128 * b as i128).rem_euclid(m as i128)) as i64
+}
+
+/// `base^exp mod m` in `0..m` by repeated squaring
+///
+/// Panics if `m` is not positive.
+pub fn mod_pow(base: i64, mut exp: u64, m: i64) -> i64 {
+    assert!(m > 0, "Modulus must be positive, got {}", m);
+    let m = m as i128;
+    let mut base = (base as i128).rem_euclid(m);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as i64
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, None if `a` and `m` are not coprime
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a, m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Chinese remainder theorem over `(residue, modulus)` pairs, the moduli need not be coprime
+///
+/// Returns `(x, lcm of the moduli)` with `x` the smallest non-negative solution, None if the
+/// congruences contradict each other, a modulus is not positive or the lcm of the moduli does not
+/// fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0i128, 1i128);
+    for &(residue, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        let modulus = modulus as i128;
+        let residue = (residue as i128).rem_euclid(modulus);
+        let g = gcd_i128(m, modulus);
+        let diff = residue - x;
+        if diff % g != 0 {
+            return None;
+        }
+
+        // Solve x + m * k ≡ residue (mod modulus) for k
+        let reduced = modulus / g;
+        let inverse = mod_inverse((m / g % reduced) as i64, reduced as i64)? as i128;
+        let k = (diff / g).rem_euclid(reduced) * inverse % reduced;
+        x += m * k;
+        m *= reduced;
+        // The combined modulus only grows, and staying within i64 keeps `m * k` within i128
+        if m > i64::MAX as i128 {
+            return None;
+        }
+        x = x.rem_euclid(m);
+    }
+    Some((x as i64, m as i64))
+}
+
+/// The largest `r` with `r * r <= n`
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method from above converges to the floor of the root
+    let n = n as u128;
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x as u64
+}
+
+pub fn is_prime(n: u64) -> bool {
+    n >= 2 && (2..=isqrt(n)).all(|d| !n.is_multiple_of(d))
+}
+
+/// Prime factors with their exponents in ascending order, empty for 0 and 1
+pub fn factorise(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    if n == 0 {
+        return factors;
+    }
+
+    let mut divisor = 2;
+    while divisor <= n / divisor {
+        let mut exponent = 0;
+        while n.is_multiple_of(divisor) {
+            n /= divisor;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((divisor, exponent));
+        }
+        divisor += if divisor == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// Every divisor of `n` in ascending order
+pub fn divisors(n: u64) -> Vec<u64> {
+    let mut result = vec![1];
+    for (prime, exponent) in factorise(n) {
+        let mut powers = Vec::new();
+        let mut power = 1;
+        for _ in 0..exponent {
+            power *= prime;
+            powers.extend(result.iter().map(|d| d * power));
+        }
+        result.extend(powers);
+    }
+    result.sort_unstable();
+    if n == 0 {
+        result.clear();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(-4, 6), 2);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, -6), 12);
+        assert_eq!(lcm(0, 5), 0);
+        assert_eq!(gcd_all([12, 18, 30]), 6);
+        assert_eq!(lcm_all(Vec::new()), Some(1));
+        // Ghost paths: every start loops with its own period and they all line up at the lcm
+        assert_eq!(lcm_all([20_093, 12_169, 22_357, 13_301, 14_999, 17_263]), Some(10_371_555_451_871));
+    }
+
+    #[test]
+    fn gcd_of_i64_min() {
+        assert_eq!(gcd(i64::MIN, 6), 2);
+        assert_eq!(checked_gcd(i64::MIN, 0), None);
+        assert_eq!(checked_gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(checked_gcd(i64::MIN, i64::MAX), Some(1));
+        assert_eq!(checked_lcm(i64::MIN, i64::MIN), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Greatest common divisor overflows i64")]
+    fn gcd_panics_on_overflow() {
+        gcd(i64::MIN, 0);
+    }
+
+    #[test]
+    fn lcm_overflow() {
+        assert_eq!(checked_lcm(4_000_000_007, 4_000_000_009), None);
+        assert_eq!(checked_lcm(-4, 6), Some(12));
+        assert_eq!(lcm_all([4_000_000_007, 4_000_000_009, 1]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Least common multiple overflows i64")]
+    fn lcm_panics_on_overflow() {
+        lcm(4_000_000_007, 4_000_000_009);
+    }
+
+    #[test]
+    fn extended_euclid() {
+        for (a, b) in [(240, 46), (-15, 35), (17, 0), (0, -9)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(2, 4), None);
+
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(-2, 3, 7), 6);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        assert_eq!(mod_pow(123_456_789, 1_000_000_006, 1_000_000_007), 1);
+
+        // Large moduli need 128-bit intermediates
+        let deck = 119_315_717_514_047;
+        let a = 2_020_202_020_202;
+        assert_eq!(mod_mul(a, mod_inverse(a, deck).unwrap(), deck), 1);
+        assert_eq!(mod_pow(a, deck as u64 - 1, deck), 1);
+        assert_eq!(mod_mul(-1, 1, deck), deck - 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus must be positive, got 0")]
+    fn mod_pow_panics_on_zero_modulus() {
+        mod_pow(2, 10, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus must be positive, got -5")]
+    fn mod_mul_panics_on_negative_modulus() {
+        mod_mul(2, 3, -5);
+    }
+
+    #[test]
+    fn chinese_remainder_bus_schedule() {
+        let buses = "7,13,x,x,59,x,31,19";
+        let congruences = buses
+            .split(',')
+            .enumerate()
+            .filter_map(|(offset, bus)| bus.parse::<i64>().ok().map(|bus| (-(offset as i64), bus)))
+            .collect::<Vec<_>>();
+        assert_eq!(crt(&congruences), Some((1_068_781, 7 * 13 * 59 * 31 * 19)));
+    }
+
+    #[test]
+    fn chinese_remainder_non_coprime() {
+        assert_eq!(crt(&[(2, 6), (8, 9)]), Some((8, 18)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(3, 4), (3, 4)]), Some((3, 4)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 3), (0, 0)]), None);
+        assert_eq!(crt(&[(1, -3)]), None);
+    }
+
+    #[test]
+    fn chinese_remainder_overflowing_modulus() {
+        assert_eq!(crt(&[(0, 4_000_000_007), (1, 4_000_000_009)]), None);
+        let (x, m) = crt(&[(0, 3_000_000_019), (1, 3_000_000_037)]).unwrap();
+        assert_eq!(m, 3_000_000_019 * 3_000_000_037);
+        assert_eq!((x % 3_000_000_019, x % 3_000_000_037), (0, 1));
+    }
+
+    #[test]
+    fn square_roots() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn primes_and_factors() {
+        assert!(is_prime(2) && is_prime(97) && !is_prime(1) && !is_prime(91));
+        assert_eq!(factorise(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorise(1), vec![]);
+        assert_eq!(factorise(600_851_475_143), vec![(71, 1), (839, 1), (1471, 1), (6857, 1)]);
+        assert_eq!(divisors(12), vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(divisors(1), vec![1]);
+        assert_eq!(divisors(0), Vec::<u64>::new());
+    }
+}
@@ -0,0 +1,291 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::number_theory::gcd_i128 as gcd;
+
+/// An exact fraction backed by `i128`, always in lowest terms with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i128,
+    denom: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+    pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+    /// `numer / denom` reduced to lowest terms, panics if `denom` is zero
+    pub fn new(numer: i128, denom: i128) -> Self {
+        assert!(denom != 0, "Denominator must not be zero");
+        let g = gcd(numer, denom);
+        let sign = denom.signum();
+        Self {
+            numer: sign * numer / g,
+            denom: sign * denom / g,
+        }
+    }
+
+    pub fn from_integer(value: i128) -> Self {
+        Self { numer: value, denom: 1 }
+    }
+
+    pub fn numer(&self) -> i128 {
+        self.numer
+    }
+
+    pub fn denom(&self) -> i128 {
+        self.denom
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numer == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == 1
+    }
+
+    /// The value as an integer, None if it has a fractional part
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.numer)
+    }
+
+    pub fn floor(&self) -> i128 {
+        self.numer.div_euclid(self.denom)
+    }
+
+    pub fn ceil(&self) -> i128 {
+        -(-self.numer).div_euclid(self.denom)
+    }
+
+    pub fn abs(&self) -> Self {
+        Self {
+            numer: self.numer.abs(),
+            denom: self.denom,
+        }
+    }
+
+    /// `1 / self`, panics if zero
+    pub fn recip(&self) -> Self {
+        Self::new(self.denom, self.numer)
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numer as f64 / self.denom as f64
+    }
+
+    /// `-self`, None if the numerator overflows `i128`
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            numer: self.numer.checked_neg()?,
+            denom: self.denom,
+        })
+    }
+
+    /// `self + rhs`, None if an intermediate overflows `i128`
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let g = gcd(self.denom, rhs.denom);
+        let numer = self
+            .numer
+            .checked_mul(rhs.denom / g)?
+            .checked_add(rhs.numer.checked_mul(self.denom / g)?)?;
+        Some(Self::new(numer, (self.denom / g).checked_mul(rhs.denom)?))
+    }
+
+    /// `self - rhs`, None if an intermediate overflows `i128`
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    /// `self * rhs`, None if an intermediate overflows `i128`
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cancel across before multiplying to keep the intermediates small
+        let g1 = gcd(self.numer, rhs.denom);
+        let g2 = gcd(rhs.numer, self.denom);
+        let numer = (self.numer / g1).checked_mul(rhs.numer / g2)?;
+        let denom = (self.denom / g2).checked_mul(rhs.denom / g1)?;
+        Some(Self::new(numer, denom))
+    }
+
+    /// `self / rhs`, None if `rhs` is zero or an intermediate overflows `i128`
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        self.checked_mul(rhs.recip())
+    }
+}
+
+const OVERFLOW: &str = "Rational arithmetic overflows i128";
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self::from_integer(value as i128)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer * other.denom).cmp(&(other.numer * self.denom))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect(OVERFLOW)
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect(OVERFLOW)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect(OVERFLOW)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect(OVERFLOW)
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// Panics when dividing by zero
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs.recip()).expect(OVERFLOW)
+    }
+}
+
+macro_rules! impl_assign_op {
+    ($($trait:ident, $method:ident, $op:tt);*) => {
+        $(
+            impl $trait for Rational {
+                fn $method(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_assign_op!(AddAssign, add_assign, +; SubAssign, sub_assign, -; MulAssign, mul_assign, *; DivAssign, div_assign, /);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalised() {
+        assert_eq!(Rational::new(6, -4), Rational::new(-3, 2));
+        assert_eq!(Rational::new(6, -4).numer(), -3);
+        assert_eq!(Rational::new(6, -4).denom(), 2);
+        assert_eq!(Rational::new(0, -5), Rational::ZERO);
+        assert_eq!(Rational::new(10, 5).to_integer(), Some(2));
+        assert_eq!(Rational::new(10, 4).to_integer(), None);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(1, 3);
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert_eq!(-half, Rational::new(-1, 2));
+
+        let mut sum = Rational::ZERO;
+        for d in 1..=10 {
+            sum += Rational::new(1, d * (d + 1));
+        }
+        assert_eq!(sum, Rational::new(10, 11));
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let big = Rational::from_integer(i128::MAX);
+        let half = Rational::new(1, 2);
+        assert_eq!(big.checked_add(Rational::ONE), None);
+        assert_eq!(big.checked_add(-Rational::ONE), Some(Rational::from_integer(i128::MAX - 1)));
+        assert_eq!((-big).checked_sub(Rational::new(2, 1)), None);
+        assert_eq!(big.checked_mul(Rational::new(2, 1)), None);
+        assert_eq!(big.checked_mul(half).map(|r| r.denom()), Some(2));
+        assert_eq!(half.checked_div(Rational::ZERO), None);
+        assert_eq!(half.checked_div(Rational::new(1, 4)), Some(Rational::from_integer(2)));
+        assert_eq!(Rational::from_integer(i128::MIN).checked_neg(), None);
+        // Denominators overflow too
+        assert_eq!(Rational::new(1, i128::MAX).checked_add(Rational::new(1, i128::MAX - 1)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational arithmetic overflows i128")]
+    fn overflow_panics() {
+        let _ = Rational::from_integer(i128::MAX) + Rational::ONE;
+    }
+
+    #[test]
+    fn ordering_and_rounding() {
+        assert!(Rational::new(-7, 2) < Rational::new(-3, 1));
+        assert!(Rational::new(2, 3) > Rational::new(3, 5));
+        assert_eq!(Rational::new(-7, 2).floor(), -4);
+        assert_eq!(Rational::new(-7, 2).ceil(), -3);
+        assert_eq!(Rational::new(7, 2).floor(), 3);
+        assert_eq!(Rational::from(4i64).ceil(), 4);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Rational::new(3, -6).to_string(), "-1/2");
+        assert_eq!(Rational::from(42i64).to_string(), "42");
+    }
+
+    #[test]
+    #[should_panic(expected = "Denominator must not be zero")]
+    fn zero_denominator() {
+        Rational::new(1, 0);
+    }
+}
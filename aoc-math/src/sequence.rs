@@ -0,0 +1,195 @@
+use crate::rational::Rational;
+use std::ops::{Add, Mul};
+
+/// Rows of repeated differences, starting with the values themselves and ending with the first
+/// row that is all zeros (or has a single value, if the sequence is not polynomial)
+pub fn difference_table(values: &[i64]) -> Vec<Vec<i64>> {
+    let mut rows = vec![values.to_vec()];
+    while let Some(last) = rows.last() {
+        if last.len() <= 1 || last.iter().all(|&v| v == 0) {
+            break;
+        }
+        let next = last.windows(2).map(|w| w[1] - w[0]).collect();
+        rows.push(next);
+    }
+    rows
+}
+
+/// The value at `index` of the lowest degree polynomial through the sequence, where `values[i]`
+/// is the value at index `i`, so `values.len()` extrapolates forwards and `-1` backwards
+///
+/// Uses Newton's forward difference formula, which stays exact in integers for any index.
+pub fn extrapolate_at(values: &[i64], index: i64) -> i128 {
+    let n = index as i128;
+    let mut binomial = 1i128;
+    let mut result = 0i128;
+    for (k, row) in difference_table(values).iter().enumerate() {
+        if k > 0 {
+            // C(n, k) from C(n, k - 1), exact since the product of k consecutive integers
+            // divides by k!
+            binomial = binomial * (n - k as i128 + 1) / k as i128;
+        }
+        result += binomial * row.first().copied().unwrap_or_default() as i128;
+    }
+    result
+}
+
+/// The next value of the sequence by finite differences
+pub fn extrapolate_next(values: &[i64]) -> i128 {
+    extrapolate_at(values, values.len() as i64)
+}
+
+/// The value before the start of the sequence by finite differences
+pub fn extrapolate_previous(values: &[i64]) -> i128 {
+    extrapolate_at(values, -1)
+}
+
+/// A polynomial with exact rational coefficients, lowest power first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    pub coefficients: Vec<Rational>,
+}
+
+impl Polynomial {
+    /// Trailing zero coefficients are dropped, the zero polynomial has no coefficients
+    pub fn new(mut coefficients: Vec<Rational>) -> Self {
+        while coefficients.last().is_some_and(Rational::is_zero) {
+            coefficients.pop();
+        }
+        Self { coefficients }
+    }
+
+    /// The unique polynomial of degree below `points.len()` through every `(x, y)` point by
+    /// Lagrange interpolation, panics if two points share an `x`
+    pub fn interpolate(points: &[(Rational, Rational)]) -> Self {
+        let mut result = Polynomial::new(Vec::new());
+        for (i, &(xi, yi)) in points.iter().enumerate() {
+            let mut basis = Polynomial::new(vec![Rational::ONE]);
+            let mut denominator = Rational::ONE;
+            for (j, &(xj, _)) in points.iter().enumerate() {
+                if i != j {
+                    assert!(xi != xj, "Interpolation points must have distinct x values");
+                    basis = basis * Polynomial::new(vec![-xj, Rational::ONE]);
+                    denominator *= xi - xj;
+                }
+            }
+            result = result + basis * Polynomial::new(vec![yi / denominator]);
+        }
+        result
+    }
+
+    /// `interpolate` for integer points, e.g. samples of a count taken at regular step numbers
+    pub fn interpolate_integers(points: &[(i64, i64)]) -> Self {
+        let points = points
+            .iter()
+            .map(|&(x, y)| (Rational::from(x), Rational::from(y)))
+            .collect::<Vec<_>>();
+        Self::interpolate(&points)
+    }
+
+    /// The highest power with a non-zero coefficient, None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// The value at `x` by Horner's method
+    pub fn evaluate(&self, x: Rational) -> Rational {
+        self.coefficients.iter().rev().fold(Rational::ZERO, |acc, &c| acc * x + c)
+    }
+}
+
+impl Add for Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        let coefficient = |p: &Polynomial, i: usize| p.coefficients.get(i).copied().unwrap_or_default();
+        Polynomial::new((0..len).map(|i| coefficient(&self, i) + coefficient(&rhs, i)).collect())
+    }
+}
+
+impl Mul for Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.coefficients.is_empty() || rhs.coefficients.is_empty() {
+            return Polynomial::new(Vec::new());
+        }
+        let mut coefficients = vec![Rational::ZERO; self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in rhs.coefficients.iter().enumerate() {
+                coefficients[i + j] += a * b;
+            }
+        }
+        Polynomial::new(coefficients)
+    }
+}
+
+/// The value at `x` of the polynomial through `points`, without building its coefficients
+pub fn lagrange_evaluate(points: &[(Rational, Rational)], x: Rational) -> Rational {
+    points.iter().enumerate().fold(Rational::ZERO, |acc, (i, &(xi, yi))| {
+        let term = points
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| i != j)
+            .fold(yi, |term, (_, &(xj, _))| term * (x - xj) / (xi - xj));
+        acc + term
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HISTORIES: [[i64; 6]; 3] = [[0, 3, 6, 9, 12, 15], [1, 3, 6, 10, 15, 21], [10, 13, 16, 21, 30, 45]];
+
+    #[test]
+    fn oasis_histories() {
+        assert_eq!(HISTORIES.iter().map(|h| extrapolate_next(h)).collect::<Vec<_>>(), vec![18, 28, 68]);
+        assert_eq!(HISTORIES.iter().map(|h| extrapolate_previous(h)).sum::<i128>(), 2);
+        assert_eq!(difference_table(&HISTORIES[2]).len(), 5);
+    }
+
+    #[test]
+    fn extrapolate_far_away() {
+        // n^3 - 2n
+        let cube = |n: i128| n * n * n - 2 * n;
+        let values = (0..5).map(|n| cube(n) as i64).collect::<Vec<_>>();
+        assert_eq!(extrapolate_at(&values, 1_000_000), cube(1_000_000));
+        assert_eq!(extrapolate_at(&values, -7), cube(-7));
+        assert_eq!(extrapolate_at(&[], 3), 0);
+        assert_eq!(extrapolate_next(&[5]), 5);
+    }
+
+    #[test]
+    fn interpolate_polynomial() {
+        let polynomial = Polynomial::interpolate_integers(&[(1, 2), (2, 3), (3, 5)]);
+        // x^2 / 2 - x / 2 + 2
+        assert_eq!(
+            polynomial.coefficients,
+            vec![Rational::from_integer(2), Rational::new(-1, 2), Rational::new(1, 2)]
+        );
+        assert_eq!(polynomial.degree(), Some(2));
+        assert_eq!(polynomial.evaluate(Rational::from_integer(10)), Rational::from_integer(47));
+
+        let line = Polynomial::interpolate_integers(&[(0, 4), (5, 4)]);
+        assert_eq!(line.degree(), Some(0));
+        assert_eq!(Polynomial::interpolate_integers(&[(0, 0), (1, 0)]).degree(), None);
+    }
+
+    #[test]
+    fn garden_steps_quadratic() {
+        // Reachable plots after 65 + 131n steps grow quadratically in n
+        let reachable = |n: i64| 14_590 * n * n + 14_655 * n + 3_648;
+        let samples = (0..3).map(|n| (n, reachable(n))).collect::<Vec<_>>();
+        let polynomial = Polynomial::interpolate_integers(&samples);
+        let target = Rational::from_integer((26_501_365 - 65) / 131);
+        assert_eq!(polynomial.evaluate(target).to_integer(), Some(reachable(202_300) as i128));
+
+        let points = samples
+            .iter()
+            .map(|&(x, y)| (Rational::from(x), Rational::from(y)))
+            .collect::<Vec<_>>();
+        assert_eq!(lagrange_evaluate(&points, target), polynomial.evaluate(target));
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Expected answers keyed by (year, day, part)
+///
+/// The file format is one answer per line as `<year> <day> <part> <answer>`, blank lines and
+/// lines starting with `#` are ignored. Fields may be separated by any run of whitespace, words
+/// of the answer are rejoined with single spaces
+#[derive(Default)]
+pub struct ExpectedAnswers {
+    answers: HashMap<(u16, u8, u8), String>,
+}
+
+impl ExpectedAnswers {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+
+        for (line_no, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            if fields.len() < 4 {
+                return Err(format!("Invalid answer on line {}: {}", line_no + 1, line));
+            }
+
+            let year = fields[0].parse::<u16>().map_err(|e| format!("Invalid year on line {}: {}", line_no + 1, e))?;
+            let day = fields[1].parse::<u8>().map_err(|e| format!("Invalid day on line {}: {}", line_no + 1, e))?;
+            let part = fields[2].parse::<u8>().map_err(|e| format!("Invalid part on line {}: {}", line_no + 1, e))?;
+            answers.insert((year, day, part), fields[3..].join(" "));
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// Loads the answers file, a missing file is treated as having no expected answers
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let input = fs::read_to_string(path).map_err(|e| format!("Unable to read answers {}: {}", path.display(), e))?;
+        Self::parse(&input)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(|s| s.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = ExpectedAnswers::parse("# comment\n\n2024 1 1 1234\n2024 1 2 hello world\n").unwrap();
+        assert_eq!(answers.get(2024, 1, 1), Some("1234"));
+        assert_eq!(answers.get(2024, 1, 2), Some("hello world"));
+        assert_eq!(answers.get(2024, 2, 1), None);
+    }
+
+    #[test]
+    fn test_parse_answers_irregular_whitespace() {
+        let answers = ExpectedAnswers::parse("2024  3\t1   42\n2024 3 2\thello \t world \n").unwrap();
+        assert_eq!(answers.get(2024, 3, 1), Some("42"));
+        assert_eq!(answers.get(2024, 3, 2), Some("hello world"));
+    }
+
+    #[test]
+    fn test_parse_answers_invalid() {
+        let result = ExpectedAnswers::parse("2024 1 1");
+        assert_eq!(result.err().unwrap(), "Invalid answer on line 1: 2024 1 1");
+
+        let result = ExpectedAnswers::parse("2024 x 1 12");
+        assert!(result.err().unwrap().starts_with("Invalid day on line 1"));
+    }
+
+    #[test]
+    fn test_load_answers() {
+        let answers = ExpectedAnswers::load("resources/answers.txt").unwrap();
+        assert_eq!(answers.get(2015, 1, 1), Some("2"));
+        assert_eq!(answers.get(2015, 1, 2), Some("5"));
+    }
+
+    #[test]
+    fn test_load_missing_answers() {
+        let answers = ExpectedAnswers::load("resources/missing.txt").unwrap();
+        assert_eq!(answers.get(2015, 1, 1), None);
+    }
+}
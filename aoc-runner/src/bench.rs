@@ -0,0 +1,249 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Controls how many times a closure is run when benchmarking
+pub struct BenchConfig {
+    /// Runs discarded before sampling starts
+    pub warmup_iterations: usize,
+    /// Number of timed runs
+    pub samples: usize,
+    /// Sampling stops early once this much time has been spent (at least one sample is always taken)
+    pub time_limit: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup_iterations: 3,
+            samples: 50,
+            time_limit: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Summary statistics of a benchmark, times are in nanoseconds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub name: String,
+    pub samples: usize,
+    pub input_bytes: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub mean_ns: u64,
+    /// Input bytes processed per second at the median time
+    pub bytes_per_sec: f64,
+}
+
+impl BenchResult {
+    /// Builds the summary from raw sample timings, panics if there are no samples
+    pub fn from_samples(name: &str, input_bytes: usize, samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Cannot summarise a benchmark without samples");
+
+        let mut nanos = samples.iter().map(|d| d.as_nanos() as u64).collect::<Vec<_>>();
+        nanos.sort_unstable();
+
+        let median_ns = if nanos.len().is_multiple_of(2) {
+            (nanos[nanos.len() / 2 - 1] + nanos[nanos.len() / 2]) / 2
+        } else {
+            nanos[nanos.len() / 2]
+        };
+        // Nearest rank percentile
+        let p95_ns = nanos[(nanos.len() * 95).div_ceil(100) - 1];
+        let mean_ns = (nanos.iter().map(|&n| n as u128).sum::<u128>() / nanos.len() as u128) as u64;
+        // Clamped so a sub-nanosecond median still gives a finite (JSON serialisable) throughput
+        let bytes_per_sec = input_bytes as f64 / (median_ns.max(1) as f64 / 1e9);
+
+        Self {
+            name: name.to_string(),
+            samples: nanos.len(),
+            input_bytes,
+            min_ns: nanos[0],
+            median_ns,
+            p95_ns,
+            mean_ns,
+            bytes_per_sec,
+        }
+    }
+}
+
+impl Display for BenchResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:<24} min {:>10} median {:>10} p95 {:>10} {:>10.2} MB/s ({} samples)",
+            self.name,
+            format!("{:.2?}", Duration::from_nanos(self.min_ns)),
+            format!("{:.2?}", Duration::from_nanos(self.median_ns)),
+            format!("{:.2?}", Duration::from_nanos(self.p95_ns)),
+            self.bytes_per_sec / 1e6,
+            self.samples
+        )
+    }
+}
+
+/// Runs `f` repeatedly according to `config` and summarises the timings
+///
+/// `input_bytes` is the size of the input `f` works on and is only used to report throughput.
+pub fn bench<F, R>(name: &str, input_bytes: usize, config: &BenchConfig, mut f: F) -> BenchResult
+where
+    F: FnMut() -> R,
+{
+    for _ in 0..config.warmup_iterations {
+        black_box(f());
+    }
+
+    let mut samples = Vec::with_capacity(config.samples);
+    let started = Instant::now();
+    while samples.len() < config.samples.max(1) {
+        let start = Instant::now();
+        black_box(f());
+        samples.push(start.elapsed());
+
+        if started.elapsed() >= config.time_limit {
+            break;
+        }
+    }
+
+    BenchResult::from_samples(name, input_bytes, &samples)
+}
+
+/// Median time of a candidate relative to a baseline with the same name
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub name: String,
+    pub baseline_median_ns: u64,
+    pub candidate_median_ns: u64,
+    /// candidate / baseline, below 1.0 means the candidate is faster
+    pub ratio: f64,
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:<24} {:>10} -> {:>10} ({:.2}x)",
+            self.name,
+            format!("{:.2?}", Duration::from_nanos(self.baseline_median_ns)),
+            format!("{:.2?}", Duration::from_nanos(self.candidate_median_ns)),
+            self.ratio
+        )
+    }
+}
+
+pub fn compare(baseline: &BenchResult, candidate: &BenchResult) -> Comparison {
+    Comparison {
+        name: candidate.name.clone(),
+        baseline_median_ns: baseline.median_ns,
+        candidate_median_ns: candidate.median_ns,
+        ratio: candidate.median_ns as f64 / baseline.median_ns.max(1) as f64,
+    }
+}
+
+/// Compares every candidate with the baseline of the same name, unmatched results are skipped
+pub fn compare_runs(baseline: &[BenchResult], candidate: &[BenchResult]) -> Vec<Comparison> {
+    candidate
+        .iter()
+        .filter_map(|c| baseline.iter().find(|b| b.name == c.name).map(|b| compare(b, c)))
+        .collect()
+}
+
+pub fn write_results<P: AsRef<Path>>(path: P, results: &[BenchResult]) -> Result<(), String> {
+    let path = path.as_ref();
+    let json = serde_json::to_string_pretty(results).map_err(|e| format!("Unable to serialise results: {}", e))?;
+    fs::write(path, json).map_err(|e| format!("Unable to write {}: {}", path.display(), e))
+}
+
+pub fn read_results<P: AsRef<Path>>(path: P) -> Result<Vec<BenchResult>, String> {
+    let path = path.as_ref();
+    let json = fs::read_to_string(path).map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+    serde_json::from_str(&json).map_err(|e| format!("Unable to parse {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&n| Duration::from_nanos(n)).collect()
+    }
+
+    #[test]
+    fn test_from_samples_statistics() {
+        let samples = nanos(&[50, 10, 40, 20, 30, 60, 70, 80, 90, 100]);
+        let result = BenchResult::from_samples("test", 1000, &samples);
+
+        assert_eq!(result.samples, 10);
+        assert_eq!(result.min_ns, 10);
+        assert_eq!(result.median_ns, 55);
+        assert_eq!(result.p95_ns, 100);
+        assert_eq!(result.mean_ns, 55);
+        assert_eq!(result.bytes_per_sec, 1000.0 / 55e-9);
+    }
+
+    #[test]
+    fn test_from_samples_odd_count() {
+        let result = BenchResult::from_samples("test", 0, &nanos(&[3, 1, 2]));
+        assert_eq!(result.median_ns, 2);
+        assert_eq!(result.p95_ns, 3);
+    }
+
+    #[test]
+    fn test_bench_runs_warmup_and_samples() {
+        let mut calls = 0;
+        let config = BenchConfig {
+            warmup_iterations: 2,
+            samples: 5,
+            time_limit: Duration::from_secs(60),
+        };
+        let result = bench("count", 10, &config, || calls += 1);
+
+        assert_eq!(calls, 7);
+        assert_eq!(result.samples, 5);
+        assert_eq!(result.name, "count");
+    }
+
+    #[test]
+    fn test_bench_time_limit() {
+        let config = BenchConfig {
+            warmup_iterations: 0,
+            samples: 1000,
+            time_limit: Duration::ZERO,
+        };
+        let result = bench("limited", 10, &config, || 1 + 1);
+        assert_eq!(result.samples, 1);
+    }
+
+    #[test]
+    fn test_compare_runs() {
+        let baseline = vec![
+            BenchResult::from_samples("a", 0, &nanos(&[100])),
+            BenchResult::from_samples("b", 0, &nanos(&[100])),
+        ];
+        let candidate = vec![
+            BenchResult::from_samples("b", 0, &nanos(&[50])),
+            BenchResult::from_samples("c", 0, &nanos(&[50])),
+        ];
+
+        let comparisons = compare_runs(&baseline, &candidate);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].name, "b");
+        assert_eq!(comparisons[0].ratio, 0.5);
+    }
+
+    #[test]
+    fn test_write_and_read_results() {
+        let path = env::temp_dir().join(format!("aoc-runner-bench-{}.json", std::process::id()));
+        let results = vec![BenchResult::from_samples("a", 42, &nanos(&[1, 2, 3]))];
+
+        write_results(&path, &results).unwrap();
+        assert_eq!(read_results(&path).unwrap(), results);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,303 @@
+use crate::answers::ExpectedAnswers;
+use crate::bench::{bench, compare_runs, read_results, write_results, BenchConfig};
+use crate::inputs::InputStore;
+use crate::runner::{run_solutions, Verdict};
+use crate::scaffold::{scaffold_day, ScaffoldOptions};
+use crate::solution::select_solutions;
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "aoc-runner", about = "Runs registered Advent of Code solutions")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run the selected solutions against the local input store
+    Run(RunArgs),
+    /// Generate a new day module and example input from templates
+    New(NewArgs),
+    /// Benchmark the selected solutions, optionally comparing with an earlier run
+    Bench(BenchArgs),
+}
+
+#[derive(Args)]
+pub struct RunArgs {
+    #[arg(short, long)]
+    pub year: Option<u16>,
+    #[arg(short, long)]
+    pub day: Option<u8>,
+    #[arg(short, long)]
+    pub part: Option<u8>,
+    /// Directory holding inputs as <year>/dayNN.txt
+    #[arg(long, default_value = "inputs")]
+    pub inputs: PathBuf,
+    /// File of expected answers as `<year> <day> <part> <answer>` lines
+    #[arg(long, default_value = "answers.txt")]
+    pub answers: PathBuf,
+}
+
+#[derive(Args)]
+pub struct NewArgs {
+    #[arg(short, long)]
+    pub year: u16,
+    #[arg(short, long)]
+    pub day: u8,
+    /// Directory the day module is written to
+    #[arg(long, default_value = "src")]
+    pub src: PathBuf,
+    /// Directory the example input placeholder is written to
+    #[arg(long, default_value = "resources")]
+    pub resources: PathBuf,
+    /// Directory of templates overriding the built in ones
+    #[arg(long, default_value = "templates")]
+    pub templates: PathBuf,
+    /// Overwrite existing files
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args)]
+pub struct BenchArgs {
+    #[arg(short, long)]
+    pub year: Option<u16>,
+    #[arg(short, long)]
+    pub day: Option<u8>,
+    #[arg(short, long)]
+    pub part: Option<u8>,
+    /// Directory holding inputs as <year>/dayNN.txt
+    #[arg(long, default_value = "inputs")]
+    pub inputs: PathBuf,
+    #[arg(long, default_value_t = 3)]
+    pub warmup: usize,
+    #[arg(long, default_value_t = 50)]
+    pub samples: usize,
+    /// Write the results as JSON to this file
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+    /// JSON results of an earlier run to compare against
+    #[arg(long)]
+    pub baseline: Option<PathBuf>,
+}
+
+/// Entry point for binaries linking in solutions, parses the command line and runs it
+pub fn main() -> ExitCode {
+    run(Cli::parse())
+}
+
+pub fn run(cli: Cli) -> ExitCode {
+    match cli.command {
+        Command::Run(args) => run_command(args),
+        Command::New(args) => new_command(args),
+        Command::Bench(args) => bench_command(args),
+    }
+}
+
+fn run_command(args: RunArgs) -> ExitCode {
+    let solutions = select_solutions(args.year, args.day, args.part);
+    if solutions.is_empty() {
+        eprintln!("No registered solutions match the selection");
+        return ExitCode::FAILURE;
+    }
+
+    let answers = match ExpectedAnswers::load(&args.answers) {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let inputs = InputStore::new(&args.inputs);
+
+    let results = run_solutions(&solutions, &inputs, &answers);
+    for result in &results {
+        println!("{}", result);
+    }
+
+    let total: Duration = results.iter().map(|r| r.elapsed).sum();
+    println!("Total: {:.2?}", total);
+
+    if results.iter().any(|r| matches!(r.verdict, Verdict::Incorrect(_) | Verdict::Failed)) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn new_command(args: NewArgs) -> ExitCode {
+    let options = ScaffoldOptions {
+        year: args.year,
+        day: args.day,
+        src_dir: args.src,
+        resources_dir: args.resources,
+        template_dir: Some(args.templates),
+        force: args.force,
+    };
+
+    match scaffold_day(&options) {
+        Ok(created) => {
+            for path in created {
+                println!("Created {}", path.display());
+            }
+            println!("Add `mod day{:02};` to your crate root to include it", options.day);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn bench_command(args: BenchArgs) -> ExitCode {
+    let solutions = select_solutions(args.year, args.day, args.part);
+    if solutions.is_empty() {
+        eprintln!("No registered solutions match the selection");
+        return ExitCode::FAILURE;
+    }
+
+    let inputs = InputStore::new(&args.inputs);
+    let config = BenchConfig {
+        warmup_iterations: args.warmup,
+        samples: args.samples,
+        ..BenchConfig::default()
+    };
+
+    let mut results = Vec::with_capacity(solutions.len());
+    for solution in solutions {
+        let input = match inputs.load(solution.year, solution.day) {
+            Ok(i) => i,
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+        let name = format!("{} day {:02} part {}", solution.year, solution.day, solution.part);
+        let result = bench(&name, input.len(), &config, || (solution.solve)(&input));
+        println!("{}", result);
+        results.push(result);
+    }
+
+    if let Some(baseline) = args.baseline {
+        match read_results(&baseline) {
+            Ok(baseline) => {
+                println!("Compared with baseline:");
+                for comparison in compare_runs(&baseline, &results) {
+                    println!("{}", comparison);
+                }
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if let Some(output) = args.output {
+        if let Err(e) = write_results(&output, &results) {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_run_args() {
+        let cli = Cli::parse_from(["aoc-runner", "run", "--year", "2024", "-d", "5", "--inputs", "data"]);
+        let Command::Run(args) = cli.command else {
+            panic!("Expected run command");
+        };
+        assert_eq!(args.year, Some(2024));
+        assert_eq!(args.day, Some(5));
+        assert_eq!(args.part, None);
+        assert_eq!(args.inputs, PathBuf::from("data"));
+        assert_eq!(args.answers, PathBuf::from("answers.txt"));
+    }
+
+    #[test]
+    fn test_parse_new_args() {
+        let cli = Cli::parse_from(["aoc-runner", "new", "-y", "2024", "-d", "12", "--force"]);
+        let Command::New(args) = cli.command else {
+            panic!("Expected new command");
+        };
+        assert_eq!(args.year, 2024);
+        assert_eq!(args.day, 12);
+        assert_eq!(args.src, PathBuf::from("src"));
+        assert_eq!(args.templates, PathBuf::from("templates"));
+        assert!(args.force);
+    }
+
+    #[test]
+    fn test_run_command() {
+        let cli = Cli::parse_from([
+            "aoc-runner",
+            "run",
+            "--year",
+            "2015",
+            "--inputs",
+            "resources/inputs",
+            "--answers",
+            "resources/answers.txt",
+        ]);
+        assert_eq!(run(cli), ExitCode::SUCCESS);
+    }
+
+    #[test]
+    fn test_bench_command() {
+        let output = std::env::temp_dir().join(format!("aoc-runner-cli-bench-{}.json", std::process::id()));
+        let output_arg = output.to_string_lossy().to_string();
+        let cli = Cli::parse_from([
+            "aoc-runner",
+            "bench",
+            "--year",
+            "2015",
+            "--inputs",
+            "resources/inputs",
+            "--samples",
+            "3",
+            "--output",
+            &output_arg,
+            "--baseline",
+            &output_arg,
+        ]);
+        // Baseline does not exist yet
+        assert_eq!(run(cli), ExitCode::FAILURE);
+
+        let cli = Cli::parse_from([
+            "aoc-runner",
+            "bench",
+            "--year",
+            "2015",
+            "--inputs",
+            "resources/inputs",
+            "--samples",
+            "3",
+            "--output",
+            &output_arg,
+        ]);
+        assert_eq!(run(cli), ExitCode::SUCCESS);
+        let results = read_results(&output).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].name, "2015 day 01 part 1");
+        assert_eq!(results[0].samples, 3);
+
+        std::fs::remove_file(&output).unwrap();
+    }
+
+    #[test]
+    fn test_run_command_no_match() {
+        let cli = Cli::parse_from(["aoc-runner", "run", "--year", "1999"]);
+        assert_eq!(run(cli), ExitCode::FAILURE);
+    }
+}
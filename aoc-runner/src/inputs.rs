@@ -0,0 +1,48 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Local store of puzzle inputs laid out as `<root>/<year>/dayNN.txt`
+pub struct InputStore {
+    root: PathBuf,
+}
+
+impl InputStore {
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.root.join(year.to_string()).join(format!("day{:02}.txt", day))
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String, String> {
+        let path = self.path(year, day);
+        fs::read_to_string(&path).map_err(|e| format!("Unable to read input {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        let store = InputStore::new("inputs");
+        assert_eq!(store.path(2024, 3), Path::new("inputs/2024/day03.txt"));
+        assert_eq!(store.path(2024, 25), Path::new("inputs/2024/day25.txt"));
+    }
+
+    #[test]
+    fn test_load_input() {
+        let store = InputStore::new("resources/inputs");
+        assert_eq!(store.load(2015, 1).unwrap().trim(), "()())(((");
+    }
+
+    #[test]
+    fn test_load_missing_input() {
+        let store = InputStore::new("resources/inputs");
+        assert!(store.load(2015, 2).unwrap_err().starts_with("Unable to read input"));
+    }
+}
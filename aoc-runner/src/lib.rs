@@ -0,0 +1,11 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod inputs;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+
+// Re-exported so the `solution!` macro works without solution crates depending on these directly
+pub use aoc_generators;
+pub use inventory;
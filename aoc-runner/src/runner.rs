@@ -0,0 +1,132 @@
+use crate::answers::ExpectedAnswers;
+use crate::inputs::InputStore;
+use crate::solution::Solution;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// Outcome of comparing an answer with the expected answers file
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    /// Answer matched the expected answer
+    Correct,
+    /// Answer did not match, holds the expected answer
+    Incorrect(String),
+    /// No expected answer is recorded for this part
+    Unverified,
+    /// The solution (or loading its input) failed
+    Failed,
+}
+
+pub struct RunResult {
+    pub solution: &'static Solution,
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+    pub verdict: Verdict,
+}
+
+impl Display for RunResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let answer = match &self.answer {
+            Ok(a) => a.clone(),
+            Err(e) => format!("error: {}", e),
+        };
+        let verdict = match &self.verdict {
+            Verdict::Correct => "ok".to_string(),
+            Verdict::Incorrect(expected) => format!("WRONG (expected {})", expected),
+            Verdict::Unverified => "-".to_string(),
+            Verdict::Failed => "FAILED".to_string(),
+        };
+        write!(
+            f,
+            "{} day {:02} part {}  {:<20} {:>12}  {}",
+            self.solution.year,
+            self.solution.day,
+            self.solution.part,
+            answer,
+            format!("{:.2?}", self.elapsed),
+            verdict
+        )
+    }
+}
+
+/// Runs a single solution against its stored input, timing only the solve (and any line processing)
+pub fn run_solution(solution: &'static Solution, inputs: &InputStore, answers: &ExpectedAnswers) -> RunResult {
+    let input = match inputs.load(solution.year, solution.day) {
+        Ok(i) => i,
+        Err(e) => {
+            return RunResult {
+                solution,
+                answer: Err(e),
+                elapsed: Duration::ZERO,
+                verdict: Verdict::Failed,
+            }
+        }
+    };
+
+    let start = Instant::now();
+    let answer = (solution.solve)(&input);
+    let elapsed = start.elapsed();
+
+    let verdict = match (&answer, answers.get(solution.year, solution.day, solution.part)) {
+        (Err(_), _) => Verdict::Failed,
+        (Ok(_), None) => Verdict::Unverified,
+        (Ok(a), Some(expected)) if a == expected => Verdict::Correct,
+        (Ok(_), Some(expected)) => Verdict::Incorrect(expected.to_string()),
+    };
+
+    RunResult {
+        solution,
+        answer,
+        elapsed,
+        verdict,
+    }
+}
+
+pub fn run_solutions(solutions: &[&'static Solution], inputs: &InputStore, answers: &ExpectedAnswers) -> Vec<RunResult> {
+    solutions.iter().map(|s| run_solution(s, inputs, answers)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::select_solutions;
+
+    #[test]
+    fn test_run_solutions_verified() {
+        let inputs = InputStore::new("resources/inputs");
+        let answers = ExpectedAnswers::load("resources/answers.txt").unwrap();
+        let solutions = select_solutions(Some(2015), Some(1), None);
+
+        let results = run_solutions(&solutions, &inputs, &answers);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].answer, Ok("2".to_string()));
+        assert_eq!(results[0].verdict, Verdict::Correct);
+        assert_eq!(results[1].answer, Ok("5".to_string()));
+        assert_eq!(results[1].verdict, Verdict::Correct);
+    }
+
+    #[test]
+    fn test_run_solution_incorrect_and_unverified() {
+        let inputs = InputStore::new("resources/inputs");
+        let answers = ExpectedAnswers::parse("2015 1 1 7").unwrap();
+        let solutions = select_solutions(Some(2015), Some(1), None);
+
+        let result = run_solution(solutions[0], &inputs, &answers);
+        assert_eq!(result.verdict, Verdict::Incorrect("7".to_string()));
+        assert!(result.to_string().ends_with("WRONG (expected 7)"));
+
+        let result = run_solution(solutions[1], &inputs, &answers);
+        assert_eq!(result.verdict, Verdict::Unverified);
+    }
+
+    #[test]
+    fn test_run_solution_missing_input() {
+        let inputs = InputStore::new("resources/missing");
+        let answers = ExpectedAnswers::default();
+        let solutions = select_solutions(Some(2015), Some(1), Some(1));
+
+        let result = run_solution(solutions[0], &inputs, &answers);
+        assert_eq!(result.verdict, Verdict::Failed);
+        assert!(result.answer.is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DAY_TEMPLATE: &str = "day.rs.template";
+pub const EXAMPLE_TEMPLATE: &str = "example.txt.template";
+
+const BUILTIN_DAY_TEMPLATE: &str = include_str!("../templates/day.rs.template");
+const BUILTIN_EXAMPLE_TEMPLATE: &str = include_str!("../templates/example.txt.template");
+
+/// Where to write a new day and which templates to use
+pub struct ScaffoldOptions {
+    pub year: u16,
+    pub day: u8,
+    /// Directory the `dayNN.rs` module is written to
+    pub src_dir: PathBuf,
+    /// Directory the `dayNN_example.txt` placeholder is written to
+    pub resources_dir: PathBuf,
+    /// Directory whose `day.rs.template`/`example.txt.template` override the built in templates
+    pub template_dir: Option<PathBuf>,
+    /// Overwrite files that already exist
+    pub force: bool,
+}
+
+/// Returns the named template from the override directory if present, else the built in one
+pub fn load_template(name: &str, template_dir: Option<&Path>) -> Result<String, String> {
+    if let Some(dir) = template_dir {
+        let path = dir.join(name);
+        if path.exists() {
+            return fs::read_to_string(&path).map_err(|e| format!("Unable to read template {}: {}", path.display(), e));
+        }
+    }
+
+    match name {
+        DAY_TEMPLATE => Ok(BUILTIN_DAY_TEMPLATE.to_string()),
+        EXAMPLE_TEMPLATE => Ok(BUILTIN_EXAMPLE_TEMPLATE.to_string()),
+        _ => Err(format!("Unknown template: {}", name)),
+    }
+}
+
+/// Substitutes `{{year}}`, `{{day}}`, `{{day_padded}}` and `{{example_path}}` in the template
+///
+/// The example path is substituted as a quoted and escaped Rust string literal.
+pub fn render(template: &str, year: u16, day: u8, example_path: &Path) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day_padded}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+        .replace("{{example_path}}", &format!("{:?}", example_path.to_string_lossy()))
+}
+
+/// Writes the day module and example placeholder, returning the paths created
+pub fn scaffold_day(options: &ScaffoldOptions) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&options.day) {
+        return Err(format!("Day must be between 1 and 25, got {}", options.day));
+    }
+
+    let module_path = options.src_dir.join(format!("day{:02}.rs", options.day));
+    let example_path = options.resources_dir.join(format!("day{:02}_example.txt", options.day));
+
+    if !options.force {
+        for path in [&module_path, &example_path] {
+            if path.exists() {
+                return Err(format!("{} already exists, use --force to overwrite", path.display()));
+            }
+        }
+    }
+
+    let template_dir = options.template_dir.as_deref();
+    let module = render(&load_template(DAY_TEMPLATE, template_dir)?, options.year, options.day, &example_path);
+    let example = render(&load_template(EXAMPLE_TEMPLATE, template_dir)?, options.year, options.day, &example_path);
+
+    for (path, content) in [(&module_path, module), (&example_path, example)] {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Unable to create {}: {}", parent.display(), e))?;
+        }
+        fs::write(path, content).map_err(|e| format!("Unable to write {}: {}", path.display(), e))?;
+    }
+
+    Ok(vec![module_path, example_path])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-runner-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_render() {
+        let rendered = render(
+            "{{year}} {{day}} {{day_padded}} {{example_path}}",
+            2024,
+            5,
+            Path::new("resources/day05_example.txt"),
+        );
+        assert_eq!(rendered, "2024 5 05 \"resources/day05_example.txt\"");
+    }
+
+    #[test]
+    fn test_render_escapes_example_path() {
+        let rendered = render("{{example_path}}", 2024, 5, Path::new("my \"puzzles\"\\day05.txt"));
+        assert_eq!(rendered, r#""my \"puzzles\"\\day05.txt""#);
+    }
+
+    #[test]
+    fn test_load_template_override() {
+        let overrides = Path::new("resources/templates");
+        let example = load_template(EXAMPLE_TEMPLATE, Some(overrides)).unwrap();
+        assert_eq!(example, "custom example for day {{day_padded}}\n");
+
+        // No override present so falls back to the built in template
+        let day = load_template(DAY_TEMPLATE, Some(overrides)).unwrap();
+        assert_eq!(day, BUILTIN_DAY_TEMPLATE);
+
+        assert!(load_template("missing.template", None).is_err());
+    }
+
+    #[test]
+    fn test_scaffold_day() {
+        let dir = scratch_dir("scaffold");
+        let mut options = ScaffoldOptions {
+            year: 2024,
+            day: 7,
+            src_dir: dir.join("src"),
+            resources_dir: dir.join("resources"),
+            template_dir: Some(PathBuf::from("resources/templates")),
+            force: false,
+        };
+
+        let created = scaffold_day(&options).unwrap();
+        assert_eq!(created, vec![dir.join("src/day07.rs"), dir.join("resources/day07_example.txt")]);
+
+        let module = fs::read_to_string(&created[0]).unwrap();
+        assert!(module.contains("pub struct Day07Processor {}"));
+        assert!(module.contains("aoc_runner::solution!(2024, 7, 1, part1);"));
+        assert!(module.contains(&format!("const EXAMPLE: &str = {:?};", created[1].to_string_lossy())));
+        assert_eq!(fs::read_to_string(&created[1]).unwrap(), "custom example for day 07\n");
+
+        // Existing files are only replaced when forced
+        assert!(scaffold_day(&options).unwrap_err().ends_with("already exists, use --force to overwrite"));
+        options.force = true;
+        assert!(scaffold_day(&options).is_ok());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_scaffold_invalid_day() {
+        let options = ScaffoldOptions {
+            year: 2024,
+            day: 26,
+            src_dir: PathBuf::from("src"),
+            resources_dir: PathBuf::from("resources"),
+            template_dir: None,
+            force: false,
+        };
+        assert_eq!(scaffold_day(&options).unwrap_err(), "Day must be between 1 and 25, got 26");
+    }
+}
@@ -0,0 +1,125 @@
+/// Signature every registered solution is normalised to by the `solution!` macro
+pub type SolveFn = fn(&str) -> Result<String, String>;
+
+/// A single registered (year, day, part) solution
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub name: &'static str,
+    pub solve: SolveFn,
+}
+
+impl Solution {
+    pub const fn new(year: u16, day: u8, part: u8, name: &'static str, solve: SolveFn) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            name,
+            solve,
+        }
+    }
+
+    pub fn matches(&self, year: Option<u16>, day: Option<u8>, part: Option<u8>) -> bool {
+        year.is_none_or(|y| y == self.year)
+            && day.is_none_or(|d| d == self.day)
+            && part.is_none_or(|p| p == self.part)
+    }
+}
+
+inventory::collect!(Solution);
+
+/// Returns every solution linked into the current binary ordered by year, day and part
+pub fn registered_solutions() -> Vec<&'static Solution> {
+    let mut solutions = inventory::iter::<Solution>.into_iter().collect::<Vec<_>>();
+    solutions.sort_by_key(|s| (s.year, s.day, s.part));
+    solutions
+}
+
+/// Returns the registered solutions matching the given filters, `None` matches everything
+pub fn select_solutions(year: Option<u16>, day: Option<u8>, part: Option<u8>) -> Vec<&'static Solution> {
+    registered_solutions()
+        .into_iter()
+        .filter(|s| s.matches(year, day, part))
+        .collect()
+}
+
+/// Registers a solution function with the runner
+///
+/// The function receives the raw puzzle input:
+/// `solution!(2015, 1, 1, part1);`
+///
+/// Or, if a [`LineProcessor`](aoc_generators::processors::line_processor_trait::LineProcessor) is
+/// supplied, the processed lines (empty lines skipped):
+/// `solution!(2015, 1, 1, part1, RegexLineProcessor::new(r"\d+", RegexLineProcessorMode::Matches));`
+///
+/// The value returned by the function only has to implement `Display`.
+#[macro_export]
+macro_rules! solution {
+    ($year:expr, $day:expr, $part:expr, $solve:path) => {
+        $crate::inventory::submit! {
+            $crate::solution::Solution::new($year, $day, $part, stringify!($solve), |input| {
+                Ok($solve(input).to_string())
+            })
+        }
+    };
+    ($year:expr, $day:expr, $part:expr, $solve:path, $processor:expr) => {
+        $crate::inventory::submit! {
+            $crate::solution::Solution::new($year, $day, $part, stringify!($solve), |input| {
+                let processor = $processor;
+                let lines = $crate::aoc_generators::apply_processor_to_input(input, &processor, true)
+                    .map_err(|e| format!("{:?}", e))?;
+                Ok($solve(lines).to_string())
+            })
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_generators::processors::regex_line_processor::{RegexLineProcessor, RegexLineProcessorMode};
+
+    fn floor(input: &str) -> i32 {
+        input.trim().chars().map(|c| if c == '(' { 1 } else { -1 }).sum()
+    }
+
+    fn basement(lines: Vec<Vec<String>>) -> usize {
+        let mut floor = 0;
+        for (idx, c) in lines[0].iter().enumerate() {
+            floor += if c == "(" { 1 } else { -1 };
+            if floor < 0 {
+                return idx + 1;
+            }
+        }
+        0
+    }
+
+    crate::solution!(2015, 1, 1, floor);
+    crate::solution!(2015, 1, 2, basement, RegexLineProcessor::new(r"[()]", RegexLineProcessorMode::Matches));
+
+    #[test]
+    fn test_registered_solutions_sorted() {
+        let solutions = select_solutions(Some(2015), Some(1), None);
+        assert_eq!(solutions.len(), 2);
+        assert_eq!(solutions[0].part, 1);
+        assert_eq!(solutions[0].name, "floor");
+        assert_eq!(solutions[1].part, 2);
+        assert_eq!(solutions[1].name, "basement");
+    }
+
+    #[test]
+    fn test_select_solutions_filters() {
+        assert_eq!(select_solutions(Some(2015), Some(1), Some(2)).len(), 1);
+        assert!(select_solutions(Some(2016), None, None).is_empty());
+        assert!(select_solutions(None, Some(25), None).is_empty());
+    }
+
+    #[test]
+    fn test_solve_raw_and_processed_input() {
+        let solutions = select_solutions(Some(2015), Some(1), None);
+        assert_eq!((solutions[0].solve)("()())((("), Ok("2".to_string()));
+        assert_eq!((solutions[1].solve)("()())((("), Ok("5".to_string()));
+    }
+}
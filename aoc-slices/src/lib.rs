@@ -1,7 +1,7 @@
 
 pub trait CountSlice {
     type Item : PartialEq;
-    fn count_slice (self: &'_ Self, slice: &'_ [Self::Item]) -> u32;
+    fn count_slice (&'_ self, slice: &'_ [Self::Item]) -> u32;
 }
 
 impl<Item : PartialEq> CountSlice for [Item] {
@@ -11,7 +11,7 @@ impl<Item : PartialEq> CountSlice for [Item] {
     {
         let len = slice.len();
 
-        self.windows(len).into_iter().filter(move | sub_slice| sub_slice == &slice).count() as u32
+        self.windows(len).filter(move | sub_slice| sub_slice == &slice).count() as u32
 
     }
 }
@@ -0,0 +1,120 @@
+//! Single dependency entry point to the aoc-rust-utils crates
+//!
+//! Optional parts of the workspace are behind cargo features: `regex`, `ndarray` (both default),
+//! `visualisation`, `parallel` and `serde`.
+
+pub use aoc_collections as collections;
+pub use aoc_generators as generators;
+pub use aoc_math as math;
+pub use aoc_slices as slices;
+
+#[cfg(feature = "visualisation")]
+pub use aoc_visualisation as visualisation;
+
+#[cfg(feature = "ndarray")]
+pub use ndarray;
+
+#[cfg(feature = "parallel")]
+pub use rayon;
+
+/// The commonly used items of every enabled crate
+pub mod prelude {
+    pub use aoc_collections::combinatorics::{n_choose_k, CartesianProduct, Combinations, IndexArrangements, Permutations, PowerSet};
+    pub use aoc_collections::count_elements;
+    pub use aoc_collections::counter::Counter;
+    pub use aoc_collections::direction::Direction;
+    pub use aoc_collections::disjoint_set::{DisjointSet, KeyedDisjointSet};
+    pub use aoc_collections::geometry::{polygon_area, polygon_area_from_instructions, polygon_area_from_path, PolygonArea};
+    pub use aoc_collections::interval_set::IntervalSet;
+    pub use aoc_collections::memo::Memoised;
+    pub use aoc_collections::ordering::{topological_sort, PrecedenceRules};
+    pub use aoc_collections::point::{Point2, Point3};
+    pub use aoc_collections::search::{find_all_matching, find_middle_element, find_next_element, find_next_matching, FindMiddleElementMode, FindMode, WrapMode};
+    pub use aoc_collections::search::bisect::{first_true, first_true_from, last_true};
+    pub use aoc_collections::search::traversal::{bfs, bfs_until, dfs, dfs_until, Traversal};
+    pub use aoc_collections::search::shortest_path::{astar, dijkstra, PathMode, ShortestPaths};
+    pub use aoc_collections::search::selection::{kth_largest, kth_smallest, mean, median, mode, percentile, StreamingMedian};
+    pub use aoc_collections::simulation::{detect_cycle, detect_cycle_brent, detect_cycle_by_key, state_at_brent, Cycle, CycleHistory};
+    #[cfg(feature = "ndarray")]
+    pub use aoc_collections::grid::Grid;
+    #[cfg(feature = "ndarray")]
+    pub use aoc_collections::region::{find_regions, find_regions_by_key, Region, Regions};
+
+    pub use aoc_generators::apply_processor_to_input;
+    pub use aoc_generators::processors::line_processor_trait::LineProcessor;
+    pub use aoc_generators::processors::range_processor::{RangeProcessor, RangeProcessorMode};
+    #[cfg(feature = "regex")]
+    pub use aoc_generators::processors::regex_line_processor::{RegexLineProcessor, RegexLineProcessorMode};
+    #[cfg(feature = "ndarray")]
+    pub use aoc_generators::processors::board_generator::{board_to_string, generate_2d_board_char};
+
+    pub use aoc_math::linear::{solve_2x2, solve_3x3, SquareSolution};
+    pub use aoc_math::number_theory::{checked_gcd, checked_lcm, crt, gcd, gcd_all, lcm, lcm_all, mod_inverse, mod_pow};
+    pub use aoc_math::rational::Rational;
+    pub use aoc_math::sequence::{extrapolate_next, extrapolate_previous, Polynomial};
+
+    pub use aoc_slices::{count_sub_slice_ref_u8, count_sub_slice_u8, CountSlice};
+
+    #[cfg(feature = "ndarray")]
+    pub use ndarray::{array, s, Array2, ArrayView2};
+
+    #[cfg(feature = "visualisation")]
+    pub use aoc_visualisation::grid::grid_utils::DisplayRowColumnNumber;
+    #[cfg(feature = "visualisation")]
+    pub use aoc_visualisation::grid::region_label::RegionLabel;
+    #[cfg(feature = "visualisation")]
+    pub use aoc_visualisation::grid::GridVisualiser;
+    #[cfg(feature = "visualisation")]
+    pub use aoc_visualisation::traits::ratatui::RatatuiStylised;
+
+    #[cfg(feature = "parallel")]
+    pub use rayon::prelude::*;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prelude::*;
+
+    #[test]
+    fn test_prelude_collections_and_slices() {
+        let input = vec![1, 2, 2, 3];
+        assert_eq!(count_elements(&input)[&2], 2);
+        assert_eq!(*find_middle_element(&input, FindMiddleElementMode::Left).unwrap(), 2);
+        assert_eq!(count_sub_slice_u8(b"abab", b"ab"), 2);
+    }
+
+    #[test]
+    fn test_prelude_ranges_into_interval_set() {
+        let processor = RangeProcessor::new(RangeProcessorMode::Dash);
+        let lines = apply_processor_to_input("3-5\n10-14\n16-20\n12-18", &processor, true).unwrap();
+        let fresh: IntervalSet<i64> = lines.into_iter().flatten().collect();
+        assert_eq!(fresh.len(), 14);
+        assert_eq!(fresh.interval_count(), 2);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_prelude_regex() {
+        let processor = RegexLineProcessor::new(r"\d+", RegexLineProcessorMode::Matches);
+        let lines = apply_processor_to_input("a1b22\nc333", &processor, true).unwrap();
+        assert_eq!(lines, vec![vec!["1", "22"], vec!["333"]]);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_prelude_ndarray() {
+        let board: Array2<char> = generate_2d_board_char("ab\ncd");
+        assert_eq!(board, array![['a', 'b'], ['c', 'd']]);
+        assert_eq!(board_to_string(&board), "ab\ncd");
+
+        let grid = Grid::from(board);
+        assert_eq!(grid.neighbours4([0, 0]).count(), 2);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_prelude_parallel() {
+        let total: i32 = (1..=100).into_par_iter().sum();
+        assert_eq!(total, 5050);
+    }
+}
@@ -1,6 +1,7 @@
 mod grid_cell;
 mod grid_config;
 pub mod grid_utils;
+pub mod region_label;
 
 use std::cmp::max;
 use crate::grid::grid_cell::GridCell;
@@ -9,8 +10,7 @@ use crate::grid::grid_utils::{DisplayRowColumnNumber, DisplayNumbersType};
 use crate::traits::ratatui::RatatuiStylised;
 use ndarray::ArrayView2;
 use ratatui::backend::Backend;
-use ratatui::buffer::Buffer;
-use ratatui::layout::{Layout, Rect};
+use ratatui::layout::Layout;
 use ratatui::style::Style;
 use ratatui::widgets::Widget;
 use ratatui::{CompletedFrame, Terminal};
@@ -118,7 +118,7 @@ impl<'a, T: Backend> GridVisualiser<'a, T> {
     }
 
 
-    pub fn draw_ref<C>(&mut self, grid: &ArrayView2<C>, row_offset:usize, col_offset:usize) -> io::Result<CompletedFrame>
+    pub fn draw_ref<C>(&mut self, grid: &ArrayView2<C>, row_offset:usize, col_offset:usize) -> io::Result<CompletedFrame<'_>>
     where
         C: RatatuiStylised,
         C: Display,
@@ -185,14 +185,14 @@ impl<'a, T: Backend> GridVisualiser<'a, T> {
                             // If it's not a numbers row or col (i.e. first of each)
                             if row_idx != 0 && col_idx != 0 {
                                 if let Some(s) = grid[[row_idx - 1, col_idx - 1]].get_style() {
-                                    grid_cell = GridCell::with_style(value, s.clone(), edge);
+                                    grid_cell = GridCell::with_style(value, s, edge);
                                 } else {
                                     grid_cell = GridCell::new(value, edge);
                                 }
                             } else {
                                 // As it's a numbers cell then we apply the numbers cell style
                                 if let Some(s) = self.row_column_number_style{
-                                    grid_cell = GridCell::with_style(value, s.clone(), edge);
+                                    grid_cell = GridCell::with_style(value, s, edge);
                                 } else {
                                     grid_cell = GridCell::new(value, edge);
                                 }
@@ -235,7 +235,7 @@ impl<'a, T: Backend> GridVisualiser<'a, T> {
                             }
 
                             if let Some(s) = grid[[row_idx, col_idx]].get_style() {
-                                grid_cell = GridCell::with_style(value, s.clone(), edge);
+                                grid_cell = GridCell::with_style(value, s, edge);
                             } else {
                                 grid_cell = GridCell::new(value, edge);
                             }
@@ -295,7 +295,8 @@ mod tests {
     use ndarray::{array, s};
 
     use ratatui::backend::TestBackend;
-    use ratatui::layout::Constraint;
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::{Constraint, Rect};
     use ratatui::style::Color;
     use std::fmt::Formatter;
     use ratatui::style::Color::Gray;
@@ -29,11 +29,11 @@ impl GridCell {
         let mut borders = Borders::TOP | Borders::LEFT;
 
         if self.edge.contains(GridCellEdge::RIGHT) {
-            borders = borders | Borders::RIGHT;
+            borders |= Borders::RIGHT;
         }
 
         if self.edge.contains(GridCellEdge::BOTTOM) {
-            borders = borders | Borders::BOTTOM;
+            borders |= Borders::BOTTOM;
         }
 
         borders
@@ -1,5 +1,6 @@
 
-#[derive(PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DisplayRowColumnNumber {
     Always,
     Never,
@@ -20,3 +21,15 @@ impl DisplayNumbersType {
 
 }
 
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_row_column_number_serde_round_trip() {
+        let json = serde_json::to_string(&DisplayRowColumnNumber::Always).unwrap();
+        assert_eq!(json, r#""Always""#);
+        assert_eq!(serde_json::from_str::<DisplayRowColumnNumber>(&json).unwrap(), DisplayRowColumnNumber::Always);
+    }
+}
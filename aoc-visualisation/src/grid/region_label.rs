@@ -0,0 +1,93 @@
+use crate::traits::ratatui::RatatuiStylised;
+use ndarray::{Array2, ArrayView2, Zip};
+use ratatui::style::{Color, Style};
+use std::fmt::{Display, Formatter};
+
+/// Background colours cycled through by region label
+const PALETTE: [Color; 12] = [
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+];
+
+/// A cell of a region label map, displayed as its original symbol on a background colour picked
+/// by its region label
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegionLabel {
+    pub label: usize,
+    pub symbol: char,
+}
+
+impl RegionLabel {
+    pub fn new(label: usize, symbol: char) -> Self {
+        Self { label, symbol }
+    }
+
+    /// Pairs a label map (e.g. `Regions::labels` from aoc-collections) with the board it labels,
+    /// ready for `GridVisualiser::draw_ref`
+    pub fn label_map(labels: &ArrayView2<usize>, symbols: &ArrayView2<char>) -> Array2<RegionLabel> {
+        Zip::from(labels).and(symbols).map_collect(|&label, &symbol| RegionLabel::new(label, symbol))
+    }
+}
+
+impl Display for RegionLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol)
+    }
+}
+
+impl RatatuiStylised for RegionLabel {
+    fn get_style(&self) -> Option<Style> {
+        Some(Style::default().bg(PALETTE[self.label % PALETTE.len()]).fg(Color::Black))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::grid_utils::DisplayRowColumnNumber;
+    use crate::grid::GridVisualiser;
+    use ndarray::array;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn test_label_map() {
+        let labels = array![[0, 0], [1, 13]];
+        let symbols = array![['A', 'A'], ['B', 'C']];
+        let map = RegionLabel::label_map(&labels.view(), &symbols.view());
+
+        assert_eq!(map[[1, 0]], RegionLabel::new(1, 'B'));
+        assert_eq!(map[[1, 1]].to_string(), "C");
+        assert_eq!(map[[0, 0]].get_style(), map[[0, 1]].get_style());
+        assert_ne!(map[[0, 0]].get_style(), map[[1, 0]].get_style());
+        // Labels beyond the palette wrap around
+        assert_eq!(map[[1, 1]].get_style(), map[[1, 0]].get_style());
+    }
+
+    #[test]
+    fn test_draw_label_map() {
+        let labels = array![[0, 1], [0, 0]];
+        let symbols = array![['A', 'B'], ['A', 'A']];
+        let map = RegionLabel::label_map(&labels.view(), &symbols.view());
+
+        let mut terminal = Terminal::new(TestBackend::new(5, 5)).unwrap();
+        let mut visualiser = GridVisualiser::new(&mut terminal, DisplayRowColumnNumber::Never);
+        assert!(visualiser.draw_ref(&map.view(), 0, 0).is_ok());
+
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(1, 1)].symbol(), "A");
+        assert_eq!(buffer[(3, 1)].symbol(), "B");
+        assert_eq!(buffer[(1, 1)].bg, Color::Red);
+        assert_eq!(buffer[(3, 1)].bg, Color::Green);
+    }
+}